@@ -0,0 +1,66 @@
+//! `#[derive(LayoutFingerprint)]`
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use quote::quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Error;
+use syn::Index;
+use syn::Member;
+use syn::parse_quote;
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream, Error> {
+	let data = match &input.data {
+		Data::Struct(a) => a,
+		_ => {
+			return Err(Error::new_spanned(
+				&input.ident,
+				"LayoutFingerprint can only be derived for structs",
+			));
+		}
+	};
+
+	// The fingerprint of the type of each field is included, so a change in
+	// the layout of a nested type changes the fingerprint of the struct.
+	let mut generics = input.generics.clone();
+	let where_clause = generics.make_where_clause();
+	for field in data.fields.iter() {
+		let ty = &field.ty;
+		where_clause
+			.predicates
+			.push(parse_quote!(#ty: ::cluFullTransmute::layout::LayoutFingerprint));
+	}
+
+	let fields = data.fields.iter().enumerate().map(|(i, field)| {
+		let member = match &field.ident {
+			Some(a) => Member::Named(a.clone()),
+			None => Member::Unnamed(Index::from(i)),
+		};
+		let name = match &field.ident {
+			Some(a) => a.to_string(),
+			None => i.to_string(),
+		};
+		let ty = &field.ty;
+		let type_name = ty.to_token_stream().to_string();
+
+		quote! {
+			.write_field::<#ty>(#name, ::core::mem::offset_of!(Self, #member), #type_name)
+			.write_u64(<#ty as ::cluFullTransmute::layout::LayoutFingerprint>::LAYOUT_FINGERPRINT)
+		}
+	});
+
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	Ok(quote! {
+		impl #impl_generics ::cluFullTransmute::layout::LayoutFingerprint
+			for #name #ty_generics #where_clause {
+			const LAYOUT_FINGERPRINT: u64 = ::cluFullTransmute::layout::FingerprintHasher::new()
+				.write_usize(::core::mem::size_of::<Self>())
+				.write_usize(::core::mem::align_of::<Self>())
+				#(#fields)*
+				.finish();
+		}
+	})
+}
@@ -0,0 +1,59 @@
+//Copyright 2019-2026 #UlinProject Denis Kotlyarov (Денис Котляров)
+
+//Licensed under the Apache License, Version 2.0 (the "License");
+//you may not use this file except in compliance with the License.
+//You may obtain a copy of the License at
+
+//	   http://www.apache.org/licenses/LICENSE-2.0
+
+//Unless required by applicable law or agreed to in writing, software
+//distributed under the License is distributed on an "AS IS" BASIS,
+//WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//See the License for the specific language governing permissions and
+// limitations under the License.
+
+// #Ulin Project 2019-2026
+/*!
+
+Derive macros for the `cluFullTransmute` crate.
+
+(Use them through the `derive` build flag of `cluFullTransmute`.)
+*/
+
+#![allow(non_snake_case)]
+#![allow(clippy::tabs_in_doc_comments)]
+
+use proc_macro::TokenStream;
+use syn::DeriveInput;
+use syn::parse_macro_input;
+
+mod layout_fingerprint;
+mod transparent_wrapper;
+
+/// Implements `cluFullTransmute::wrapper::TransparentWrapper` for a
+/// `#[repr(transparent)]` struct.
+///
+/// If the struct has more than one field, the wrapped (non-ZST) field must be
+/// marked with `#[transparent_wrapper]`, all other fields must be `PhantomData`.
+#[proc_macro_derive(TransparentWrapper, attributes(transparent_wrapper))]
+pub fn derive_transparent_wrapper(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	transparent_wrapper::expand(&input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Implements `cluFullTransmute::layout::LayoutFingerprint` for a struct.
+///
+/// The fingerprint is calculated from the size and alignment of the struct
+/// and the names, offsets, sizes, alignments, type names and fingerprints of
+/// its fields (the type of each field must implement `LayoutFingerprint`).
+#[proc_macro_derive(LayoutFingerprint)]
+pub fn derive_layout_fingerprint(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	layout_fingerprint::expand(&input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
@@ -0,0 +1,132 @@
+//! `#[derive(TransparentWrapper)]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Error;
+use syn::Field;
+use syn::Type;
+
+const ATTR_NAME: &str = "transparent_wrapper";
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream, Error> {
+	check_repr_transparent(input)?;
+
+	let data = match &input.data {
+		Data::Struct(a) => a,
+		_ => {
+			return Err(Error::new_spanned(
+				&input.ident,
+				"TransparentWrapper can only be derived for structs",
+			));
+		}
+	};
+
+	let inner: &Field = {
+		let mut fields = data.fields.iter();
+
+		match (fields.next(), fields.next()) {
+			(Some(a), None) => a,
+			(None, _) => {
+				return Err(Error::new_spanned(
+					&input.ident,
+					"TransparentWrapper requires a struct with a wrapped field",
+				));
+			}
+			(Some(_), Some(_)) => {
+				let mut marked = data
+					.fields
+					.iter()
+					.filter(|a| a.attrs.iter().any(|a| a.path().is_ident(ATTR_NAME)));
+
+				let inner = match (marked.next(), marked.next()) {
+					(Some(a), None) => a,
+					_ => {
+						return Err(Error::new_spanned(
+							&input.ident,
+							"TransparentWrapper requires exactly one field marked with `#[transparent_wrapper]` if the struct has more than one field",
+						));
+					}
+				};
+
+				// `#[repr(transparent)]` allows only one non-ZST field, it must be
+				// the marked one, so all other fields must be `PhantomData`.
+				if let Some(a) = data
+					.fields
+					.iter()
+					.find(|a| !std::ptr::eq(*a, inner) && !is_phantom_data(&a.ty))
+				{
+					return Err(Error::new_spanned(
+						a,
+						"TransparentWrapper requires all fields except the `#[transparent_wrapper]` field to be `PhantomData`",
+					));
+				}
+
+				inner
+			}
+		}
+	};
+
+	let name = &input.ident;
+	let inner_ty = &inner.ty;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	// The sizes can only be checked at the declaration for a non-generic
+	// struct, otherwise the check is done by `TYPE_LAYOUT_MATCH_ASSERT` on use.
+	let size_check = match input.generics.params.is_empty() {
+		true => quote! {
+			const _: () = ::core::assert!(
+				::core::mem::size_of::<#name>() == ::core::mem::size_of::<#inner_ty>(),
+				"TransparentWrapper: the size of the wrapper must match the size of the wrapped type"
+			);
+		},
+		false => quote! {},
+	};
+
+	Ok(quote! {
+		#size_check
+
+		unsafe impl #impl_generics ::cluFullTransmute::wrapper::TransparentWrapper<#inner_ty>
+			for #name #ty_generics #where_clause {}
+	})
+}
+
+/// Whether the type is `PhantomData<..>` (or `()`), a ZST that does not
+/// affect the layout.
+fn is_phantom_data(ty: &Type) -> bool {
+	match ty {
+		Type::Path(a) => a
+			.path
+			.segments
+			.last()
+			.is_some_and(|a| a.ident == "PhantomData"),
+		Type::Tuple(a) => a.elems.is_empty(),
+		Type::Paren(a) => is_phantom_data(&a.elem),
+		Type::Group(a) => is_phantom_data(&a.elem),
+		_ => false,
+	}
+}
+
+/// The layout of the struct must be guaranteed by `#[repr(transparent)]`.
+fn check_repr_transparent(input: &DeriveInput) -> Result<(), Error> {
+	let mut is_transparent = false;
+
+	for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("transparent") {
+				is_transparent = true;
+			}
+
+			Ok(())
+		})?;
+	}
+
+	match is_transparent {
+		true => Ok(()),
+		false => Err(Error::new_spanned(
+			&input.ident,
+			"TransparentWrapper requires `#[repr(transparent)]`",
+		)),
+	}
+}
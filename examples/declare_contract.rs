@@ -0,0 +1,23 @@
+use cluFullTransmute::declare_contract;
+
+/*
+	The same contract as in `examples/contract.rs`, but the safe wrapper
+	is generated by the `declare_contract!` macro.
+*/
+
+declare_contract! {
+	/// `&'static str` that can always be read as `&'static [u8]`.
+	#[derive(Debug, Clone, Copy, PartialEq)]
+	unsafe struct MyData: &'static str => &'static [u8];
+}
+
+fn main() {
+	const C_DATA: &str = "Test";
+	const DATA: MyData = MyData::new(C_DATA);
+
+	assert_eq!(*DATA.as_in(), C_DATA); // const_readtype: &'static str
+	assert_eq!(*DATA.as_out(), C_DATA.as_bytes()); // const_readtype &'static [u8]
+
+	let vec: &'static [u8] = DATA.into(); // const_transmute: &'static str -> &'static [u8]
+	assert_eq!(vec, C_DATA.as_bytes());
+}
@@ -0,0 +1,403 @@
+//! Fixed-size byte arrays with a selectable alignment.
+//!
+//! `[u8; N]` is aligned to 1 byte, so a reference to it cannot be safely
+//! reinterpreted as a reference to a type with `u64` fields. `RawBytes` has
+//! the alignment of `A` and can be viewed as any type of size `N` that is not
+//! more aligned than `A`.
+//!
+//! ```rust
+//! use cluFullTransmute::aligned::Align8;
+//! use cluFullTransmute::aligned::AlignedBytes;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Header {
+//! 	magic: u64,
+//! 	len: u64,
+//! }
+//!
+//! unsafe impl cluFullTransmute::bytes::AnyBitPattern for Header {}
+//! unsafe impl cluFullTransmute::bytes::Zeroable for Header {}
+//!
+//! let data: AlignedBytes<16, Align8> = AlignedBytes::new([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+//!
+//! let header: &Header = data.as_value();
+//! assert_eq!(header.magic, u64::from_ne_bytes([1, 0, 0, 0, 0, 0, 0, 0]));
+//! ```
+
+use crate::bytes::AnyBitPattern;
+use crate::bytes::NoPadding;
+use crate::bytes::Zeroable;
+use crate::try_transmute_or_panic;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::mem::align_of;
+use core::mem::size_of;
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+macro_rules! decl_align {
+	[ $( $name:ident = $align:literal ),* $(,)? ] => {
+		$(
+			#[doc = concat!("Alignment marker of ", stringify!($align), " bytes (zero-sized).")]
+			#[repr(align($align))]
+			#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+			pub struct $name;
+		)*
+	};
+}
+
+decl_align![
+	Align1 = 1,
+	Align2 = 2,
+	Align4 = 4,
+	Align8 = 8,
+	Align16 = 16,
+	Align32 = 32,
+	Align64 = 64,
+];
+
+/// Alignment marker of the alignment of `T` (zero-sized).
+#[repr(C)]
+pub struct AlignOf<T>([T; 0]);
+
+impl<T> AlignOf<T> {
+	/// Create a marker.
+	#[inline]
+	pub const fn new() -> Self {
+		Self([])
+	}
+}
+
+impl<T> Default for AlignOf<T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> Clone for AlignOf<T>
+where
+	T: Copy,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for AlignOf<T> where T: Copy {}
+
+impl<T> Debug for AlignOf<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		f.debug_tuple("AlignOf").field(&align_of::<T>()).finish()
+	}
+}
+
+/// `N` bytes aligned as `A` (`Align1`..`Align64`, `AlignOf<T>` or any other type).
+///
+/// `N` must be a multiple of the alignment of `A` (so that the type has no
+/// trailing padding), this is checked at compile time.
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::aligned::Align8;
+/// use cluFullTransmute::aligned::RawBytes;
+///
+/// let data = RawBytes::<12, Align8>::zeroed();
+/// ```
+#[repr(C)]
+pub struct RawBytes<const N: usize, A = u8> {
+	_align: [A; 0],
+	bytes: [u8; N],
+}
+
+/// `N` bytes aligned as the alignment marker (`Align1`..`Align64`, `AlignOf<T>`).
+pub type AlignedBytes<const N: usize, Align> = RawBytes<N, Align>;
+
+/// Checking that `T` can be viewed in `RawBytes<N, A>` at compile time
+struct ViewCheck<T, const N: usize, A>(PhantomData<(T, A)>);
+
+impl<T, const N: usize, A> ViewCheck<T, N, A> {
+	const TYPE_VIEW_ASSERT: () = [()][
+		// If you read this in the error logs, then the size of `T` is not `N`
+		// or `T` is more aligned than `A`.
+		(size_of::<T>() != N || align_of::<T>() > align_of::<A>()) as usize
+	];
+}
+
+/// Checking that `[T]` can be viewed in `RawBytes<N, A>` at compile time
+struct SliceCheck<T, const N: usize, A>(PhantomData<(T, A)>);
+
+impl<T, const N: usize, A> SliceCheck<T, N, A> {
+	const TYPE_SLICE_ASSERT: () = [()][
+		// If you read this in the error logs, then `N` is not a multiple of the
+		// size of `T` (or `T` is zero-sized), or `T` is more aligned than `A`.
+		(size_of::<T>() == 0
+			|| !N.is_multiple_of(size_of::<T>())
+			|| align_of::<T>() > align_of::<A>()) as usize
+	];
+}
+
+impl<const N: usize, A> RawBytes<N, A> {
+	/// Checking the absence of trailing padding at compile time
+	#[doc(hidden)]
+	pub const TYPE_SIZE_ASSERT: () = [()][
+		// If you read this in the error logs, then `N` is not a multiple of
+		// the alignment of `A`.
+		(size_of::<Self>() != N) as usize
+	];
+
+	/// Create aligned bytes.
+	#[inline]
+	pub const fn new(bytes: [u8; N]) -> Self {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_raw_size = Self::TYPE_SIZE_ASSERT;
+
+		Self { _align: [], bytes }
+	}
+
+	/// Create zeroed bytes.
+	#[inline]
+	pub const fn zeroed() -> Self {
+		Self::new([0; N])
+	}
+
+	/// Converts the value (of size `N`) to aligned bytes.
+	///
+	/// The function is completely constant, in case the size of `T` does not
+	/// match `N`, a panic pops up.
+	#[track_caller]
+	#[inline]
+	pub const fn from_value<T>(value: T) -> Self
+	where
+		T: NoPadding,
+	{
+		Self::new(unsafe { try_transmute_or_panic(value) })
+	}
+
+	/// Converts the bytes to the value (of size `N`).
+	///
+	/// The function is completely constant, in case the size of `T` does not
+	/// match `N`, a panic pops up.
+	#[track_caller]
+	#[inline]
+	pub const fn into_value<T>(self) -> T
+	where
+		T: AnyBitPattern,
+	{
+		unsafe { try_transmute_or_panic(self.bytes) }
+	}
+
+	/// View the bytes as a reference to `T`, the size of `T` must be `N` and
+	/// `T` must not be more aligned than `A` (checked at compile time).
+	#[inline]
+	pub const fn as_value<T>(&self) -> &T
+	where
+		T: AnyBitPattern,
+	{
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_value_view = ViewCheck::<T, N, A>::TYPE_VIEW_ASSERT;
+
+		unsafe { &*(self as *const Self as *const T) }
+	}
+
+	/// View the bytes as a mutable reference to `T`, the size of `T` must be
+	/// `N` and `T` must not be more aligned than `A` (checked at compile time).
+	#[inline]
+	pub const fn as_value_mut<T>(&mut self) -> &mut T
+	where
+		T: AnyBitPattern + NoPadding,
+	{
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_value_view = ViewCheck::<T, N, A>::TYPE_VIEW_ASSERT;
+
+		unsafe { &mut *(self as *mut Self as *mut T) }
+	}
+
+	/// View the bytes as a slice of `T`, `N` must be a multiple of the size of
+	/// `T` and `T` must not be more aligned than `A` (checked at compile time).
+	#[inline]
+	pub const fn as_slice<T>(&self) -> &[T]
+	where
+		T: AnyBitPattern,
+	{
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_slice_view = SliceCheck::<T, N, A>::TYPE_SLICE_ASSERT;
+
+		unsafe { core::slice::from_raw_parts(self as *const Self as *const T, N / size_of::<T>()) }
+	}
+
+	/// View the bytes as a mutable slice of `T`, `N` must be a multiple of the
+	/// size of `T` and `T` must not be more aligned than `A` (checked at compile time).
+	#[inline]
+	pub const fn as_slice_mut<T>(&mut self) -> &mut [T]
+	where
+		T: AnyBitPattern + NoPadding,
+	{
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_slice_view = SliceCheck::<T, N, A>::TYPE_SLICE_ASSERT;
+
+		unsafe { core::slice::from_raw_parts_mut(self as *mut Self as *mut T, N / size_of::<T>()) }
+	}
+
+	/// Get a reference to the bytes.
+	#[inline]
+	pub const fn as_bytes(&self) -> &[u8; N] {
+		&self.bytes
+	}
+
+	/// Get a mutable reference to the bytes.
+	#[inline]
+	pub const fn as_bytes_mut(&mut self) -> &mut [u8; N] {
+		&mut self.bytes
+	}
+
+	/// Get the bytes.
+	#[inline]
+	pub const fn into_bytes(self) -> [u8; N] {
+		self.bytes
+	}
+}
+
+unsafe impl<const N: usize, A> AnyBitPattern for RawBytes<N, A> where A: Copy + 'static {}
+unsafe impl<const N: usize, A> Zeroable for RawBytes<N, A> {}
+
+impl<const N: usize, A> Clone for RawBytes<N, A>
+where
+	A: Copy,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<const N: usize, A> Copy for RawBytes<N, A> where A: Copy {}
+
+impl<const N: usize, A> Default for RawBytes<N, A> {
+	#[inline]
+	fn default() -> Self {
+		Self::zeroed()
+	}
+}
+
+impl<const N: usize, A> PartialEq for RawBytes<N, A> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.bytes == other.bytes
+	}
+}
+
+impl<const N: usize, A> Eq for RawBytes<N, A> {}
+
+impl<const N: usize, A> Debug for RawBytes<N, A> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		Debug::fmt(&self.bytes, f)
+	}
+}
+
+impl<const N: usize, A> Deref for RawBytes<N, A> {
+	type Target = [u8; N];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.bytes
+	}
+}
+
+impl<const N: usize, A> DerefMut for RawBytes<N, A> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.bytes
+	}
+}
+
+impl<const N: usize, A> From<[u8; N]> for RawBytes<N, A> {
+	#[inline]
+	fn from(bytes: [u8; N]) -> Self {
+		Self::new(bytes)
+	}
+}
+
+/// Embeds the file (`include_bytes!`) as `&'static RawBytes<N, A>` aligned as `A`.
+///
+/// ```rust
+/// use cluFullTransmute::aligned::Align8;
+/// use cluFullTransmute::aligned::RawBytes;
+/// use cluFullTransmute::include_aligned_bytes;
+///
+/// static DATA: &RawBytes<16, Align8> = include_aligned_bytes!(Align8, "../tests/data/u64x2.bin");
+///
+/// let values: &[u64; 2] = DATA.as_value();
+/// assert_eq!(values, &[u64::from_le(1), u64::from_le(2)]);
+/// ```
+#[macro_export]
+macro_rules! include_aligned_bytes {
+	[ $align:ty, $path:expr $(,)? ] => {
+		const { &$crate::aligned::RawBytes::<_, $align>::new(*::core::include_bytes!($path)) }
+	};
+}
+
+/// Embeds the file (`include_bytes!`) aligned for `T` and reinterprets it as
+/// `&'static T` in a const context.
+///
+/// `T` must implement `bytes::AnyBitPattern`, the size of the file must be the
+/// size of `T` (checked at compile time).
+///
+/// ```rust
+/// use cluFullTransmute::include_transmuted;
+///
+/// static TABLE: &[u32; 4] = include_transmuted!("../tests/data/u64x2.bin" as [u32; 4]);
+///
+/// assert_eq!(TABLE[0], u32::from_le(1));
+/// assert_eq!(TABLE[2], u32::from_le(2));
+/// ```
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::include_transmuted;
+///
+/// static TABLE: &[u32; 3] = include_transmuted!("../tests/data/u64x2.bin" as [u32; 3]);
+/// ```
+#[macro_export]
+macro_rules! include_transmuted {
+	[ $path:literal as $ty:ty ] => {
+		const {
+			const DATA: &$crate::aligned::RawBytes<{ ::core::include_bytes!($path).len() }, $ty> =
+				&$crate::aligned::RawBytes::new(*::core::include_bytes!($path));
+
+			DATA.as_value::<$ty>()
+		}
+	};
+}
+
+/// Embeds the file (`include_bytes!`) aligned for `T` and reinterprets it as
+/// `&'static [T]` in a const context.
+///
+/// `T` must implement `bytes::AnyBitPattern`, the size of the file must be a
+/// multiple of the size of `T` (checked at compile time).
+///
+/// ```rust
+/// use cluFullTransmute::include_transmuted_slice;
+///
+/// static RECORDS: &[u64] = include_transmuted_slice!("../tests/data/u64x2.bin" as u64);
+///
+/// assert_eq!(RECORDS, [u64::from_le(1), u64::from_le(2)]);
+/// ```
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::include_transmuted_slice;
+///
+/// static RECORDS: &[[u8; 3]] = include_transmuted_slice!("../tests/data/u64x2.bin" as [u8; 3]);
+/// ```
+#[macro_export]
+macro_rules! include_transmuted_slice {
+	[ $path:literal as $ty:ty ] => {
+		const {
+			const DATA: &$crate::aligned::RawBytes<{ ::core::include_bytes!($path).len() }, $ty> =
+				&$crate::aligned::RawBytes::new(*::core::include_bytes!($path));
+
+			DATA.as_slice::<$ty>()
+		}
+	};
+}
@@ -0,0 +1,387 @@
+//! Atomic cells for small padding-free `Copy` types and atomic views over
+//! plain integers.
+//!
+//! ```rust
+//! use cluFullTransmute::atomic::AtomicCell;
+//! use cluFullTransmute::bytes::NoPadding;
+//! use core::sync::atomic::Ordering;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct State {
+//! 	generation: u16,
+//! 	flags: u16,
+//! }
+//!
+//! unsafe impl NoPadding for State {}
+//!
+//! let state = AtomicCell::new(State { generation: 0, flags: 0 });
+//!
+//! let current = state.load(Ordering::Acquire);
+//! let next = State { generation: current.generation + 1, flags: 1 };
+//! assert_eq!(state.compare_exchange(current, next, Ordering::AcqRel, Ordering::Acquire), Ok(current));
+//! assert_eq!(state.load(Ordering::Acquire), next);
+//!
+//! let mut buffer: [u64; 4] = [0; 4];
+//! let slots = cluFullTransmute::atomic::slice_as_atomic_u64(&mut buffer).unwrap();
+//! slots[1].store(10, Ordering::Release);
+//! assert_eq!(buffer, [0, 10, 0, 0]);
+//! ```
+
+use crate::bytes::NoPadding;
+use crate::err::TransmuteErr;
+use crate::err::TransmuteErrKind;
+use crate::try_transmute_or_panic;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::mem::align_of;
+use core::mem::size_of;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicI8;
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::AtomicI16;
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::AtomicI32;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicI64;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicIsize;
+use core::sync::atomic::AtomicU8;
+use core::sync::atomic::AtomicU16;
+use core::sync::atomic::AtomicU32;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+/// The atomic integer matching the size of the value.
+#[repr(C)]
+union AtomicStorage {
+	a8: ManuallyDrop<AtomicU8>,
+	a16: ManuallyDrop<AtomicU16>,
+	a32: ManuallyDrop<AtomicU32>,
+	#[cfg(target_has_atomic = "64")]
+	a64: ManuallyDrop<AtomicU64>,
+}
+
+/// Calls `$e` with `$a` (the atomic integer matching the size of `T`).
+macro_rules! match_atomic {
+	[ $storage:expr, |$a:ident| $e:expr ] => {
+		match size_of::<T>() {
+			1 => {
+				let $a: &AtomicU8 = unsafe { &$storage.a8 };
+				$e
+			}
+			2 => {
+				let $a: &AtomicU16 = unsafe { &$storage.a16 };
+				$e
+			}
+			4 => {
+				let $a: &AtomicU32 = unsafe { &$storage.a32 };
+				$e
+			}
+			#[cfg(target_has_atomic = "64")]
+			8 => {
+				let $a: &AtomicU64 = unsafe { &$storage.a64 };
+				$e
+			}
+			_ => unreachable!(),
+		}
+	};
+}
+
+/// A cell for `T` (1, 2, 4 or 8 bytes) stored as the atomic integer of the
+/// same size (`AtomicU8`, `AtomicU16`, `AtomicU32`, `AtomicU64`).
+///
+/// `T` must not contain padding bytes (`NoPadding`), so `compare_exchange`
+/// compares the values and not the random contents of the padding.
+///
+/// Other sizes are rejected at compile time:
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::atomic::AtomicCell;
+///
+/// let cell = AtomicCell::new([0u8; 3]);
+/// ```
+pub struct AtomicCell<T> {
+	storage: AtomicStorage,
+	_pp: PhantomData<T>,
+}
+
+unsafe impl<T> Sync for AtomicCell<T> where T: Send {}
+
+impl<T> AtomicCell<T>
+where
+	T: NoPadding,
+{
+	/// Checking the size of the value at compile time
+	#[doc(hidden)]
+	pub const TYPE_SIZE_ASSERT: () = [()][
+		// If you read this in the error logs, then there is no atomic integer
+		// of the size of `T` (1, 2, 4 or 8 bytes).
+		(!matches!(size_of::<T>(), 1 | 2 | 4 | 8)
+			|| (cfg!(not(target_has_atomic = "64")) && size_of::<T>() == 8)) as usize
+	];
+
+	/// Create a new cell.
+	#[inline]
+	pub const fn new(value: T) -> Self {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_atomic_size = Self::TYPE_SIZE_ASSERT;
+
+		let storage = unsafe {
+			match size_of::<T>() {
+				1 => AtomicStorage {
+					a8: ManuallyDrop::new(AtomicU8::new(try_transmute_or_panic(value))),
+				},
+				2 => AtomicStorage {
+					a16: ManuallyDrop::new(AtomicU16::new(try_transmute_or_panic(value))),
+				},
+				4 => AtomicStorage {
+					a32: ManuallyDrop::new(AtomicU32::new(try_transmute_or_panic(value))),
+				},
+				#[cfg(target_has_atomic = "64")]
+				8 => AtomicStorage {
+					a64: ManuallyDrop::new(AtomicU64::new(try_transmute_or_panic(value))),
+				},
+				_ => unreachable!(),
+			}
+		};
+
+		Self {
+			storage,
+			_pp: PhantomData,
+		}
+	}
+
+	/// Loads the value.
+	#[inline]
+	pub fn load(&self, order: Ordering) -> T {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_atomic_size = Self::TYPE_SIZE_ASSERT;
+
+		match_atomic!(self.storage, |a| unsafe {
+			try_transmute_or_panic(a.load(order))
+		})
+	}
+
+	/// Stores the value.
+	#[inline]
+	pub fn store(&self, value: T, order: Ordering) {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_atomic_size = Self::TYPE_SIZE_ASSERT;
+
+		match_atomic!(self.storage, |a| unsafe {
+			a.store(try_transmute_or_panic(value), order)
+		})
+	}
+
+	/// Stores the value, returning the previous value.
+	#[inline]
+	pub fn swap(&self, value: T, order: Ordering) -> T {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_atomic_size = Self::TYPE_SIZE_ASSERT;
+
+		match_atomic!(self.storage, |a| unsafe {
+			try_transmute_or_panic(a.swap(try_transmute_or_panic(value), order))
+		})
+	}
+
+	/// Stores `new` if the current value is bitwise equal to `current`.
+	///
+	/// The result is `Ok` with the previous value if the value was written,
+	/// otherwise `Err` with the current value.
+	#[inline]
+	pub fn compare_exchange(
+		&self,
+		current: T,
+		new: T,
+		success: Ordering,
+		failure: Ordering,
+	) -> Result<T, T> {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_atomic_size = Self::TYPE_SIZE_ASSERT;
+
+		match_atomic!(self.storage, |a| unsafe {
+			match a.compare_exchange(
+				try_transmute_or_panic(current),
+				try_transmute_or_panic(new),
+				success,
+				failure,
+			) {
+				Ok(a) => Ok(try_transmute_or_panic(a)),
+				Err(a) => Err(try_transmute_or_panic(a)),
+			}
+		})
+	}
+
+	/// Get a mutable reference to the value.
+	#[inline]
+	pub fn get_mut(&mut self) -> &mut T {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_atomic_size = Self::TYPE_SIZE_ASSERT;
+
+		// The storage is at least as large and aligned as `T`, and contains a valid `T`.
+		unsafe { &mut *(&mut self.storage as *mut AtomicStorage as *mut T) }
+	}
+
+	/// Get the value.
+	#[inline]
+	pub fn into_inner(self) -> T {
+		self.load(Ordering::Relaxed)
+	}
+}
+
+impl<T> Default for AtomicCell<T>
+where
+	T: NoPadding + Default,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T> From<T> for AtomicCell<T>
+where
+	T: NoPadding,
+{
+	#[inline]
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<T> Debug for AtomicCell<T>
+where
+	T: NoPadding + Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		f.debug_tuple("AtomicCell")
+			.field(&self.load(Ordering::Relaxed))
+			.finish()
+	}
+}
+
+/// Checks that the plain integer `P` can be viewed as the atomic integer `A`.
+///
+/// The alignment is checked by types, an atomic integer can be more aligned
+/// than the plain integer of the same size (e.g. `u64` on x86).
+const fn check_atomic<P, A>() -> Result<(), TransmuteErrKind> {
+	// Data dimension check
+	let size_d = size_of::<P>();
+	let size_to = size_of::<A>();
+	if size_d != size_to {
+		return Err(TransmuteErrKind::size_mismatch(size_d, size_to));
+	}
+
+	// Data alignment check
+	let align_d = align_of::<P>();
+	let align_to = align_of::<A>();
+	if align_d < align_to {
+		return Err(TransmuteErrKind::align_mismatch(align_d, align_to));
+	}
+
+	Ok(())
+}
+
+/// Checks that the atomic integer `A` can be viewed as the plain integer `P`.
+///
+/// Unlike [check_atomic], the atomic integer may be more aligned than the
+/// plain integer, only the reverse is an error.
+const fn check_atomic_snapshot<P, A>() -> Result<(), TransmuteErrKind> {
+	// Data dimension check
+	let size_d = size_of::<A>();
+	let size_to = size_of::<P>();
+	if size_d != size_to {
+		return Err(TransmuteErrKind::size_mismatch(size_d, size_to));
+	}
+
+	// Data alignment check
+	let align_d = align_of::<A>();
+	let align_to = align_of::<P>();
+	if align_d < align_to {
+		return Err(TransmuteErrKind::align_mismatch(align_d, align_to));
+	}
+
+	Ok(())
+}
+
+macro_rules! atomic_view {
+	[ $(
+		#[cfg(target_has_atomic = $width:literal)]
+		$t:ident: $atomic:ident => $as_atomic:ident, $slice_as_atomic:ident, $atomic_slice_as:ident
+	);* $(;)? ] => {
+		$(
+			#[doc = concat!("View the `", stringify!($t), "` as `", stringify!($atomic), "`.")]
+			///
+			/// If the alignment of the atomic integer is larger than the alignment
+			/// of the plain integer on the target, an error occurs.
+			#[cfg(target_has_atomic = $width)]
+			#[inline]
+			pub const fn $as_atomic(value: &mut $t) -> Result<&$atomic, TransmuteErr<&mut $t>> {
+				if let Err(e) = check_atomic::<$t, $atomic>() {
+					return Err(TransmuteErr::new(e, value));
+				}
+
+				Ok(unsafe { &*(value as *mut $t as *const $atomic) })
+			}
+
+			#[doc = concat!("View the `[", stringify!($t), "]` as `[", stringify!($atomic), "]`.")]
+			///
+			/// If the alignment of the atomic integer is larger than the alignment
+			/// of the plain integer on the target, an error occurs.
+			#[cfg(target_has_atomic = $width)]
+			#[inline]
+			pub const fn $slice_as_atomic(values: &mut [$t]) -> Result<&[$atomic], TransmuteErr<&mut [$t]>> {
+				if let Err(e) = check_atomic::<$t, $atomic>() {
+					return Err(TransmuteErr::new(e, values));
+				}
+
+				Ok(unsafe { core::slice::from_raw_parts(values.as_mut_ptr() as *const $atomic, values.len()) })
+			}
+
+			#[doc = concat!("View the `[", stringify!($atomic), "]` as a snapshot `[", stringify!($t), "]`.")]
+			///
+			/// # Safety
+			///
+			/// The atomic integers must not be changed while the returned slice is alive.
+			#[cfg(target_has_atomic = $width)]
+			#[inline]
+			pub const unsafe fn $atomic_slice_as(values: &[$atomic]) -> &[$t] {
+				if let Err(e) = check_atomic_snapshot::<$t, $atomic>() {
+					// (the atomic integer is always at least as aligned as the plain integer)
+					e.unwrap();
+				}
+
+				unsafe { core::slice::from_raw_parts(values.as_ptr() as *const $t, values.len()) }
+			}
+		)*
+	};
+}
+
+atomic_view! {
+	#[cfg(target_has_atomic = "8")]
+	u8: AtomicU8 => as_atomic_u8, slice_as_atomic_u8, atomic_slice_as_u8;
+	#[cfg(target_has_atomic = "8")]
+	i8: AtomicI8 => as_atomic_i8, slice_as_atomic_i8, atomic_slice_as_i8;
+	#[cfg(target_has_atomic = "16")]
+	u16: AtomicU16 => as_atomic_u16, slice_as_atomic_u16, atomic_slice_as_u16;
+	#[cfg(target_has_atomic = "16")]
+	i16: AtomicI16 => as_atomic_i16, slice_as_atomic_i16, atomic_slice_as_i16;
+	#[cfg(target_has_atomic = "32")]
+	u32: AtomicU32 => as_atomic_u32, slice_as_atomic_u32, atomic_slice_as_u32;
+	#[cfg(target_has_atomic = "32")]
+	i32: AtomicI32 => as_atomic_i32, slice_as_atomic_i32, atomic_slice_as_i32;
+	#[cfg(target_has_atomic = "64")]
+	u64: AtomicU64 => as_atomic_u64, slice_as_atomic_u64, atomic_slice_as_u64;
+	#[cfg(target_has_atomic = "64")]
+	i64: AtomicI64 => as_atomic_i64, slice_as_atomic_i64, atomic_slice_as_i64;
+	#[cfg(target_has_atomic = "ptr")]
+	usize: AtomicUsize => as_atomic_usize, slice_as_atomic_usize, atomic_slice_as_usize;
+	#[cfg(target_has_atomic = "ptr")]
+	isize: AtomicIsize => as_atomic_isize, slice_as_atomic_isize, atomic_slice_as_isize;
+}
@@ -0,0 +1,233 @@
+//! Conversion of values to and from their byte representation.
+//!
+//! ```rust
+//! use cluFullTransmute::bytes;
+//!
+//! let value: u32 = 0x01020304;
+//!
+//! let a: &[u8] = bytes::as_bytes(&value);
+//! let b: [u8; 4] = bytes::to_bytes(value);
+//! assert_eq!(a, b);
+//!
+//! let c: u32 = bytes::read_from(a).unwrap();
+//! assert_eq!(c, value);
+//! ```
+
+use crate::err::TransmuteErr;
+use crate::err::TransmuteErrKind;
+use core::mem::align_of;
+use core::mem::size_of;
+use core::mem::size_of_val;
+
+pub use crate::marker::AnyBitPattern;
+pub use crate::marker::NoPadding;
+pub use crate::marker::Zeroable;
+
+/// View the value as its bytes.
+#[inline]
+pub const fn as_bytes<T>(value: &T) -> &[u8]
+where
+	T: NoPadding,
+{
+	unsafe { core::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+}
+
+/// View the value as its mutable bytes.
+#[inline]
+pub const fn as_bytes_mut<T>(value: &mut T) -> &mut [u8]
+where
+	T: NoPadding + AnyBitPattern,
+{
+	unsafe { core::slice::from_raw_parts_mut(value as *mut T as *mut u8, size_of::<T>()) }
+}
+
+/// View the slice as its bytes.
+#[inline]
+pub const fn slice_as_bytes<T>(values: &[T]) -> &[u8]
+where
+	T: NoPadding,
+{
+	unsafe { core::slice::from_raw_parts(values.as_ptr() as *const u8, size_of_val(values)) }
+}
+
+/// View the slice as its mutable bytes.
+#[inline]
+pub const fn slice_as_bytes_mut<T>(values: &mut [T]) -> &mut [u8]
+where
+	T: NoPadding + AnyBitPattern,
+{
+	unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u8, size_of_val(values)) }
+}
+
+/// Converts the value to an array of its bytes.
+///
+/// The function is completely constant, in case `N` does not match the
+/// size of `T`, a panic pops up.
+#[track_caller]
+#[inline]
+pub const fn to_bytes<T, const N: usize>(value: T) -> [u8; N]
+where
+	T: NoPadding,
+{
+	unsafe { crate::try_transmute_or_panic(value) }
+}
+
+/// Reinterprets the bytes as a reference to `T`.
+///
+/// If the length of the bytes does not match the size of `T`, or the bytes
+/// are not aligned for `T`, an error occurs.
+#[inline]
+pub fn from_bytes<T>(bytes: &[u8]) -> Result<&T, TransmuteErr<&[u8]>>
+where
+	T: AnyBitPattern,
+{
+	if let Err(e) = check_layout::<T>(bytes.len(), bytes.as_ptr()) {
+		return Err(TransmuteErr::new(e, bytes));
+	}
+
+	Ok(unsafe { &*(bytes.as_ptr() as *const T) })
+}
+
+/// Reinterprets the mutable bytes as a mutable reference to `T`.
+///
+/// If the length of the bytes does not match the size of `T`, or the bytes
+/// are not aligned for `T`, an error occurs.
+#[inline]
+pub fn from_bytes_mut<T>(bytes: &mut [u8]) -> Result<&mut T, TransmuteErr<&mut [u8]>>
+where
+	T: AnyBitPattern + NoPadding,
+{
+	if let Err(e) = check_layout::<T>(bytes.len(), bytes.as_ptr()) {
+		return Err(TransmuteErr::new(e, bytes));
+	}
+
+	Ok(unsafe { &mut *(bytes.as_mut_ptr() as *mut T) })
+}
+
+/// Reads a copy of `T` from the bytes, the bytes do not need to be aligned.
+///
+/// If the length of the bytes does not match the size of `T`, an error occurs.
+#[inline]
+pub const fn read_from<T>(bytes: &[u8]) -> Result<T, TransmuteErr<&[u8]>>
+where
+	T: AnyBitPattern,
+{
+	{
+		// Data dimension check
+		let size_d = bytes.len();
+		let size_to = size_of::<T>();
+
+		if size_d != size_to {
+			let err = TransmuteErr::size_mismatch(size_d, size_to, bytes);
+
+			return Err(err);
+		}
+	}
+
+	Ok(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const T) })
+}
+
+/// Compares the bytes of two values.
+#[inline]
+pub const fn bytes_eq<T>(a: &T, b: &T) -> bool
+where
+	T: NoPadding,
+{
+	let a = as_bytes(a);
+	let b = as_bytes(b);
+
+	let mut i = 0;
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+		i += 1;
+	}
+
+	true
+}
+
+/// Calculates the hash of the bytes of the value (64-bit FNV-1a).
+///
+/// The result is stable and does not depend on the platform,
+/// except for the byte order of the value itself.
+#[inline]
+pub const fn bytes_hash<T>(value: &T) -> u64
+where
+	T: NoPadding,
+{
+	fnv1a(FNV_OFFSET_BASIS, as_bytes(value))
+}
+
+/// Initial state of the FNV-1a 64 hash.
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+/// Continues the FNV-1a 64 hash `hash` with the bytes.
+#[inline]
+pub(crate) const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+	const FNV_PRIME: u64 = 0x100000001b3;
+
+	let mut i = 0;
+	while i < bytes.len() {
+		hash ^= bytes[i] as u64;
+		hash = hash.wrapping_mul(FNV_PRIME);
+		i += 1;
+	}
+
+	hash
+}
+
+/// Checks that `len` bytes at `ptr` can be viewed as `T`.
+#[inline]
+pub(crate) fn check_layout<T>(len: usize, ptr: *const u8) -> Result<(), TransmuteErrKind> {
+	// Data dimension check
+	let size_to = size_of::<T>();
+	if len != size_to {
+		return Err(TransmuteErrKind::size_mismatch(len, size_to));
+	}
+
+	check_align::<T>(ptr)
+}
+
+/// Checks that `len` bytes consist of whole values of `T`, returns the number
+/// of `T` values.
+///
+/// The one slice size check shared by `ptr`, `view`, `mmap` and `to`.
+#[inline]
+pub(crate) const fn check_slice_len<T>(len: usize) -> Result<usize, TransmuteErrKind> {
+	// Data dimension check
+	let size_to = size_of::<T>();
+	if size_to == 0 || !len.is_multiple_of(size_to) {
+		return Err(TransmuteErrKind::size_mismatch(len, size_to));
+	}
+
+	Ok(len / size_to)
+}
+
+/// Checks that `len` bytes at `ptr` can be viewed as `[T]`, returns the number
+/// of `T` values.
+///
+/// An empty slice is not checked for the alignment, it is never dereferenced.
+#[inline]
+pub(crate) fn check_slice<T>(len: usize, ptr: *const u8) -> Result<usize, TransmuteErrKind> {
+	let count = check_slice_len::<T>(len)?;
+	if len != 0 {
+		check_align::<T>(ptr)?;
+	}
+
+	Ok(count)
+}
+
+/// Checks that `ptr` is aligned for `T`.
+#[inline]
+pub(crate) fn check_align<T>(ptr: *const u8) -> Result<(), TransmuteErrKind> {
+	// Data alignment check
+	let align_to = align_of::<T>();
+	if !ptr.addr().is_multiple_of(align_to) {
+		let align_d = 1 << ptr.addr().trailing_zeros();
+
+		return Err(TransmuteErrKind::align_mismatch(align_d, align_to));
+	}
+
+	Ok(())
+}
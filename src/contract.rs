@@ -1,19 +1,74 @@
 //! Data Transformation TransmuteContract.
 
 use crate::mem::transmute_unchecked;
+use crate::wrapper::TransparentWrapper;
+use core::borrow::Borrow;
+use core::borrow::BorrowMut;
 use core::cmp::Ordering;
 use core::fmt::Debug;
+use core::fmt::Display;
 use core::fmt::Formatter;
+use core::future::Future;
 use core::hash::Hash;
 use core::hash::Hasher;
+use core::iter::FusedIterator;
 use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
 use core::mem::size_of;
 use core::ops::Deref;
 use core::ops::DerefMut;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+/// A blanket agreement that any value of `Self` may be reinterpreted as `OUT`.
+///
+/// Safe constructors of a contract (`Default`, `From<IN>`) cannot ask the
+/// caller for guarantees, so they are only available for pairs of types
+/// that have signed this agreement in advance.
+///
+/// Only the identity agreement (`T => T`) is provided, any other pair must be
+/// signed by the user:
+///
+/// ```rust
+/// use cluFullTransmute::contract::TransmuteContract;
+/// use cluFullTransmute::contract::TrustedContract;
+///
+/// #[repr(transparent)]
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Meters(u32);
+///
+/// // Every `u32` is a valid `Meters`.
+/// unsafe impl TrustedContract<Meters> for u32 {}
+///
+/// #[derive(Default)]
+/// struct Config {
+/// 	distance: TransmuteContract<u32, Meters>,
+/// }
+///
+/// assert_eq!(Config::default().distance.into(), Meters(0));
+/// ```
+///
+/// # Safety
+///
+/// Every valid value of `Self` must also be a valid value of `OUT`, and
+/// `size_of::<Self>()` must be equal to `size_of::<OUT>()`.
+pub unsafe trait TrustedContract<OUT> {}
+
+unsafe impl<T> TrustedContract<T> for T {}
 
 /// A contract for converting or reading data of related types.
 /// Creating such a contract is not safe because only the creator of
 /// the contract can guarantee that the converted type will match.
+///
+/// # Pinning
+///
+/// The data of the contract is structurally pinned: the contract never
+/// moves its data out of a pinned reference, has no `Drop` of its own,
+/// and is `Unpin` only when both `IN` and `OUT` are `Unpin`. Therefore
+/// `Pin<&mut TransmuteContract<IN, OUT>>` can be projected both to
+/// `Pin<&mut IN>` and to `Pin<&mut OUT>` (see [TransmuteContract::as_pin_in],
+/// [TransmuteContract::as_pin_out]).
 #[repr(transparent)]
 pub struct TransmuteContract<IN, OUT> {
 	data: IN,
@@ -116,6 +171,137 @@ where
 	}
 }
 
+impl<IN, OUT> Copy for TransmuteContract<IN, OUT> where IN: Copy {}
+
+/// Only for agreed pairs of types (see [TrustedContract]).
+impl<IN, OUT> Default for TransmuteContract<IN, OUT>
+where
+	IN: Default + TrustedContract<OUT>,
+{
+	#[inline]
+	fn default() -> Self {
+		let new_data = Default::default();
+
+		unsafe { Self::new_unchecked(new_data) }
+	}
+}
+
+/// Only for agreed pairs of types (see [TrustedContract]).
+impl<IN, OUT> From<IN> for TransmuteContract<IN, OUT>
+where
+	IN: TrustedContract<OUT>,
+{
+	#[inline]
+	fn from(data: IN) -> Self {
+		unsafe { Self::new_unchecked(data) }
+	}
+}
+
+/// A contract can be wrapped only for agreed pairs of types (see [TrustedContract]).
+unsafe impl<IN, OUT> TransparentWrapper<IN> for TransmuteContract<IN, OUT> where
+	IN: TrustedContract<OUT>
+{
+}
+
+impl<IN, OUT> Display for TransmuteContract<IN, OUT>
+where
+	IN: Display,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		Display::fmt(&self.data as &IN, f)
+	}
+}
+
+impl<IN, OUT> AsRef<IN> for TransmuteContract<IN, OUT> {
+	#[inline]
+	fn as_ref(&self) -> &IN {
+		self.as_in()
+	}
+}
+
+impl<IN, OUT> AsMut<IN> for TransmuteContract<IN, OUT> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut IN {
+		self.as_mut_in()
+	}
+}
+
+impl<IN, OUT> Borrow<IN> for TransmuteContract<IN, OUT> {
+	#[inline]
+	fn borrow(&self) -> &IN {
+		self.as_in()
+	}
+}
+
+impl<IN, OUT> BorrowMut<IN> for TransmuteContract<IN, OUT> {
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut IN {
+		self.as_mut_in()
+	}
+}
+
+impl<IN, OUT> Iterator for TransmuteContract<IN, OUT>
+where
+	IN: Iterator,
+{
+	type Item = IN::Item;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Iterator::next(&mut self.data as &mut IN)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		Iterator::size_hint(&self.data as &IN)
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		Iterator::nth(&mut self.data as &mut IN, n)
+	}
+}
+
+impl<IN, OUT> DoubleEndedIterator for TransmuteContract<IN, OUT>
+where
+	IN: DoubleEndedIterator,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		DoubleEndedIterator::next_back(&mut self.data as &mut IN)
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		DoubleEndedIterator::nth_back(&mut self.data as &mut IN, n)
+	}
+}
+
+impl<IN, OUT> ExactSizeIterator for TransmuteContract<IN, OUT>
+where
+	IN: ExactSizeIterator,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		ExactSizeIterator::len(&self.data as &IN)
+	}
+}
+
+impl<IN, OUT> FusedIterator for TransmuteContract<IN, OUT> where IN: FusedIterator {}
+
+impl<IN, OUT> Future for TransmuteContract<IN, OUT>
+where
+	IN: Future,
+{
+	type Output = IN::Output;
+
+	#[inline]
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		Future::poll(self.as_pin_in(), cx)
+	}
+}
+
 impl<IN, OUT> TransmuteContract<IN, OUT> {
 	/// Checking contract sizes at compile time
 	const TYPE_SIZE_MATCH_ASSERT: () = [()][
@@ -181,6 +367,64 @@ impl<IN, OUT> TransmuteContract<IN, OUT> {
 		}
 	}
 
+	/// Get a pinned link to the data.
+	#[inline]
+	pub const fn as_pin_ref_in(self: Pin<&Self>) -> Pin<&IN> {
+		let sself: &Self = Pin::get_ref(self);
+
+		// The data is structurally pinned (see `Pinning`).
+		unsafe { Pin::new_unchecked(sself.as_in()) }
+	}
+
+	/// Get a pinned link to the mutable data.
+	#[inline]
+	pub const fn as_pin_in(self: Pin<&mut Self>) -> Pin<&mut IN> {
+		let sself: &mut Self = unsafe { Pin::get_unchecked_mut(self) };
+
+		// The data is structurally pinned (see `Pinning`).
+		unsafe { Pin::new_unchecked(sself.as_mut_in()) }
+	}
+
+	/// Getting a pinned pseudo-pointer to the converted value without substitution.
+	#[inline]
+	#[track_caller]
+	pub const fn as_pin_ref_out(self: Pin<&Self>) -> Pin<&OUT> {
+		let sself: &Self = Pin::get_ref(self);
+
+		// The data is structurally pinned (see `Pinning`).
+		unsafe { Pin::new_unchecked(sself.as_out()) }
+	}
+
+	/// Getting a pinned mutable pseudo-pointer to the converted value without substitution.
+	#[inline]
+	#[track_caller]
+	pub const fn as_pin_out(self: Pin<&mut Self>) -> Pin<&mut OUT> {
+		let sself: &mut Self = unsafe { Pin::get_unchecked_mut(self) };
+
+		// The data is structurally pinned (see `Pinning`).
+		unsafe { Pin::new_unchecked(sself.as_mut_out()) }
+	}
+
+	/// Get a view of the contract that compares, hashes and formats
+	/// through the converted `OUT` value.
+	#[inline]
+	pub const fn as_out_view(&self) -> &AsOut<IN, OUT> {
+		AsOut::from_ref(self)
+	}
+
+	/// Get a view of the contract that is formatted with `Debug`
+	/// through the converted `OUT` value.
+	#[inline]
+	pub const fn debug_out(&self) -> &DebugOut<IN, OUT> {
+		DebugOut::from_ref(self)
+	}
+
+	/// Make the contract drop its data as `OUT` instead of `IN`.
+	#[inline]
+	pub const fn drop_as_out(self) -> DropAsOut<IN, OUT> {
+		DropAsOut::new(self)
+	}
+
 	/// Ignoring the contract, the requirement to return the data back.
 	#[inline]
 	#[track_caller]
@@ -207,6 +451,38 @@ impl<IN, OUT> TransmuteContract<IN, OUT> {
 	}
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl<IN, OUT> TransmuteContract<IN, OUT> {
+	/// Checking the alignment of contract types at compile time
+	const TYPE_ALIGN_MATCH_ASSERT: () = [()][
+		// If you read this in the error logs, then the allocation of `IN` cannot be
+		// reused for `OUT`: the alignments of the input and output types must match.
+		(core::mem::align_of::<IN>() != core::mem::align_of::<OUT>()) as usize
+	];
+
+	/// Get the pinned data without moving it out of the allocation.
+	#[inline]
+	pub fn into_pin_in(self: Pin<alloc::boxed::Box<Self>>) -> Pin<alloc::boxed::Box<IN>> {
+		// This is allowed because we have repr transparent,
+		// the data remains pinned in the same allocation.
+		unsafe { transmute_unchecked(self) }
+	}
+
+	/// Execute the contract for the pinned data without moving it
+	/// out of the allocation.
+	#[inline]
+	#[track_caller]
+	pub fn into_pin_out(self: Pin<alloc::boxed::Box<Self>>) -> Pin<alloc::boxed::Box<OUT>> {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_input_and_output_type_alignments = Self::TYPE_ALIGN_MATCH_ASSERT;
+
+		// The data remains pinned in the same allocation,
+		// whose layout is also valid for `OUT`.
+		unsafe { transmute_unchecked(self) }
+	}
+}
+
 impl<IN, OUT> Deref for TransmuteContract<IN, OUT> {
 	type Target = IN;
 
@@ -222,3 +498,598 @@ impl<IN, OUT> DerefMut for TransmuteContract<IN, OUT> {
 		self.as_mut_in()
 	}
 }
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+mod serde {
+	use crate::contract::TransmuteContract;
+	use crate::contract::TrustedContract;
+	use serde::Deserialize;
+	use serde::Deserializer;
+	use serde::Serialize;
+	use serde::Serializer;
+
+	/// The contract is serialized transparently, as `IN`.
+	impl<IN, OUT> Serialize for TransmuteContract<IN, OUT>
+	where
+		IN: Serialize,
+	{
+		#[inline]
+		fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			Serialize::serialize(self.as_in(), serializer)
+		}
+	}
+
+	/// The contract is deserialized from `IN`, the data cannot be checked,
+	/// so only agreed pairs of types are allowed (see [TrustedContract]).
+	///
+	/// For a pair of different types (`IN` != `OUT`) the agreement must be
+	/// signed by the user with `unsafe impl TrustedContract<OUT> for IN {}`.
+	impl<'de, IN, OUT> Deserialize<'de> for TransmuteContract<IN, OUT>
+	where
+		IN: Deserialize<'de> + TrustedContract<OUT>,
+	{
+		#[inline]
+		fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			let data: IN = Deserialize::deserialize(deserializer)?;
+
+			Ok(unsafe { Self::new_unchecked(data) })
+		}
+	}
+}
+
+/// A contract that compares, hashes and formats through the
+/// converted `OUT` value instead of `IN`.
+#[repr(transparent)]
+pub struct AsOut<IN, OUT>(TransmuteContract<IN, OUT>);
+
+impl<IN, OUT> AsOut<IN, OUT> {
+	/// Wrap the contract.
+	#[inline]
+	pub const fn new(contract: TransmuteContract<IN, OUT>) -> Self {
+		Self(contract)
+	}
+
+	/// Get a view of an existing contract.
+	#[inline]
+	pub const fn from_ref(contract: &TransmuteContract<IN, OUT>) -> &Self {
+		// This is allowed because we have repr transparent.
+		unsafe { transmute_unchecked(contract) }
+	}
+
+	/// Get a link to the contract.
+	#[inline]
+	pub const fn as_contract(&self) -> &TransmuteContract<IN, OUT> {
+		&self.0
+	}
+
+	/// Return the contract back.
+	#[inline]
+	pub const fn into_contract(self) -> TransmuteContract<IN, OUT> {
+		// This is allowed because we have repr transparent.
+		unsafe { transmute_unchecked(self) }
+	}
+}
+
+impl<IN, OUT> From<TransmuteContract<IN, OUT>> for AsOut<IN, OUT> {
+	#[inline]
+	fn from(contract: TransmuteContract<IN, OUT>) -> Self {
+		Self::new(contract)
+	}
+}
+
+unsafe impl<IN, OUT> TransparentWrapper<TransmuteContract<IN, OUT>> for AsOut<IN, OUT> {}
+
+impl<IN, OUT> Deref for AsOut<IN, OUT> {
+	type Target = TransmuteContract<IN, OUT>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_contract()
+	}
+}
+
+impl<IN, OUT> Clone for AsOut<IN, OUT>
+where
+	IN: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self(Clone::clone(&self.0))
+	}
+}
+
+impl<IN, OUT> Copy for AsOut<IN, OUT> where IN: Copy {}
+
+impl<IN, OUT> Debug for AsOut<IN, OUT>
+where
+	OUT: Debug,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		Debug::fmt(self.0.as_out(), f)
+	}
+}
+
+impl<IN, OUT> Display for AsOut<IN, OUT>
+where
+	OUT: Display,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		Display::fmt(self.0.as_out(), f)
+	}
+}
+
+impl<IN, OUT> PartialEq for AsOut<IN, OUT>
+where
+	OUT: PartialEq,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		PartialEq::eq(self.0.as_out(), other.0.as_out())
+	}
+}
+
+impl<IN, OUT> Eq for AsOut<IN, OUT> where OUT: Eq {}
+
+impl<IN, OUT> PartialOrd for AsOut<IN, OUT>
+where
+	OUT: PartialOrd,
+{
+	#[inline]
+	fn partial_cmp(&self, o: &Self) -> Option<Ordering> {
+		PartialOrd::partial_cmp(self.0.as_out(), o.0.as_out())
+	}
+}
+
+impl<IN, OUT> Ord for AsOut<IN, OUT>
+where
+	OUT: Ord,
+{
+	#[inline]
+	fn cmp(&self, c: &Self) -> Ordering {
+		Ord::cmp(self.0.as_out(), c.0.as_out())
+	}
+}
+
+impl<IN, OUT> Hash for AsOut<IN, OUT>
+where
+	OUT: Hash,
+{
+	#[inline]
+	fn hash<H>(&self, h: &mut H)
+	where
+		H: Hasher,
+	{
+		Hash::hash(self.0.as_out(), h)
+	}
+}
+
+/// A contract that is formatted with `Debug` through the converted
+/// `OUT` value, all other traits still work through `IN`.
+#[repr(transparent)]
+pub struct DebugOut<IN, OUT>(TransmuteContract<IN, OUT>);
+
+impl<IN, OUT> DebugOut<IN, OUT> {
+	/// Wrap the contract.
+	#[inline]
+	pub const fn new(contract: TransmuteContract<IN, OUT>) -> Self {
+		Self(contract)
+	}
+
+	/// Get a view of an existing contract.
+	#[inline]
+	pub const fn from_ref(contract: &TransmuteContract<IN, OUT>) -> &Self {
+		// This is allowed because we have repr transparent.
+		unsafe { transmute_unchecked(contract) }
+	}
+
+	/// Get a link to the contract.
+	#[inline]
+	pub const fn as_contract(&self) -> &TransmuteContract<IN, OUT> {
+		&self.0
+	}
+
+	/// Return the contract back.
+	#[inline]
+	pub const fn into_contract(self) -> TransmuteContract<IN, OUT> {
+		// This is allowed because we have repr transparent.
+		unsafe { transmute_unchecked(self) }
+	}
+}
+
+impl<IN, OUT> From<TransmuteContract<IN, OUT>> for DebugOut<IN, OUT> {
+	#[inline]
+	fn from(contract: TransmuteContract<IN, OUT>) -> Self {
+		Self::new(contract)
+	}
+}
+
+unsafe impl<IN, OUT> TransparentWrapper<TransmuteContract<IN, OUT>> for DebugOut<IN, OUT> {}
+
+impl<IN, OUT> Deref for DebugOut<IN, OUT> {
+	type Target = TransmuteContract<IN, OUT>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_contract()
+	}
+}
+
+impl<IN, OUT> Clone for DebugOut<IN, OUT>
+where
+	IN: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self(Clone::clone(&self.0))
+	}
+}
+
+impl<IN, OUT> Copy for DebugOut<IN, OUT> where IN: Copy {}
+
+impl<IN, OUT> Debug for DebugOut<IN, OUT>
+where
+	OUT: Debug,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		Debug::fmt(self.0.as_out(), f)
+	}
+}
+
+impl<IN, OUT> PartialEq for DebugOut<IN, OUT>
+where
+	IN: PartialEq,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		PartialEq::eq(&self.0, &other.0)
+	}
+}
+
+impl<IN, OUT> Eq for DebugOut<IN, OUT> where IN: Eq {}
+
+impl<IN, OUT> PartialOrd for DebugOut<IN, OUT>
+where
+	IN: PartialOrd,
+{
+	#[inline]
+	fn partial_cmp(&self, o: &Self) -> Option<Ordering> {
+		PartialOrd::partial_cmp(&self.0, &o.0)
+	}
+}
+
+impl<IN, OUT> Ord for DebugOut<IN, OUT>
+where
+	IN: Ord,
+{
+	#[inline]
+	fn cmp(&self, c: &Self) -> Ordering {
+		Ord::cmp(&self.0, &c.0)
+	}
+}
+
+impl<IN, OUT> Hash for DebugOut<IN, OUT>
+where
+	IN: Hash,
+{
+	#[inline]
+	fn hash<H>(&self, h: &mut H)
+	where
+		H: Hasher,
+	{
+		Hash::hash(&self.0, h)
+	}
+}
+
+/// A contract whose data is dropped as the converted `OUT` value
+/// instead of `IN`.
+///
+/// This is useful when the real owner semantics belong to `OUT`,
+/// e.g. an FFI handle contracted as a Rust RAII wrapper.
+///
+/// The wrapper is not `Clone` (a copy of `IN` would be released twice
+/// as `OUT`) and does not give out the contract itself, neither shared (the
+/// contract is `Clone`) nor mutable (replacing it would drop the old data as
+/// `IN`), only the data.
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::contract::TransmuteContract;
+///
+/// let data = unsafe { TransmuteContract::<u32, u32>::new_unchecked(1) }.drop_as_out();
+/// let copy: TransmuteContract<u32, u32> = *data;
+/// ```
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::contract::TransmuteContract;
+///
+/// let data = unsafe { TransmuteContract::<u32, u32>::new_unchecked(1) }.drop_as_out();
+/// let copy: TransmuteContract<u32, u32> = data.as_contract().clone();
+/// ```
+#[repr(transparent)]
+pub struct DropAsOut<IN, OUT>(ManuallyDrop<TransmuteContract<IN, OUT>>);
+
+impl<IN, OUT> DropAsOut<IN, OUT> {
+	/// Wrap the contract.
+	#[inline]
+	pub const fn new(contract: TransmuteContract<IN, OUT>) -> Self {
+		Self(ManuallyDrop::new(contract))
+	}
+
+	/// Get a link to the contract (not public, the contract could be copied).
+	#[inline]
+	const fn contract(&self) -> &TransmuteContract<IN, OUT> {
+		let data: &ManuallyDrop<TransmuteContract<IN, OUT>> = &self.0;
+
+		// This is allowed because we have repr transparent.
+		unsafe { transmute_unchecked(data) }
+	}
+
+	/// Get a link to the data.
+	#[inline]
+	pub const fn as_in(&self) -> &IN {
+		self.contract().as_in()
+	}
+
+	/// Getting a pseudo-pointer to the converted value without substitution.
+	#[inline]
+	#[track_caller]
+	pub const fn as_out(&self) -> &OUT {
+		self.contract().as_out()
+	}
+
+	/// Get a link to the mutable data.
+	///
+	/// (the contract itself cannot be replaced, a value replaced through
+	/// this link is dropped as `IN`, see [DropAsOut::as_mut_out])
+	#[inline]
+	pub const fn as_mut_in(&mut self) -> &mut IN {
+		let data: &mut ManuallyDrop<TransmuteContract<IN, OUT>> = &mut self.0;
+		let data: &mut TransmuteContract<IN, OUT> = unsafe { transmute_unchecked(data) };
+
+		// This is allowed because we have repr transparent.
+		data.as_mut_in()
+	}
+
+	/// Getting a mutable pseudo-pointer to the converted value without substitution,
+	/// a value replaced through this link is dropped as `OUT`.
+	#[inline]
+	#[track_caller]
+	pub const fn as_mut_out(&mut self) -> &mut OUT {
+		let data: &mut ManuallyDrop<TransmuteContract<IN, OUT>> = &mut self.0;
+		let data: &mut TransmuteContract<IN, OUT> = unsafe { transmute_unchecked(data) };
+
+		// This is allowed because we have repr transparent.
+		data.as_mut_out()
+	}
+
+	/// Return the contract back, the data will be dropped as `IN` again.
+	#[inline]
+	#[track_caller]
+	pub const fn into_contract(self) -> TransmuteContract<IN, OUT> {
+		// To implement permanent movement without calling `Drop`:
+		let sself: Self = self;
+		let data: TransmuteContract<IN, OUT> = unsafe { transmute_unchecked(sself) };
+
+		// This is allowed because we have repr transparent.
+
+		data
+	}
+
+	/// Ignoring the contract, the requirement to return the data back.
+	#[inline]
+	#[track_caller]
+	pub const fn release_indata(self) -> IN {
+		self.into_contract().release_indata()
+	}
+
+	/// Execute the contract and return a value with the new data type.
+	#[inline]
+	#[track_caller]
+	pub const fn into(self) -> OUT {
+		self.into_contract().into()
+	}
+}
+
+impl<IN, OUT> Drop for DropAsOut<IN, OUT> {
+	#[inline]
+	fn drop(&mut self) {
+		let contract = unsafe { ManuallyDrop::take(&mut self.0) };
+
+		drop::<OUT>(contract.into());
+	}
+}
+
+impl<IN, OUT> From<TransmuteContract<IN, OUT>> for DropAsOut<IN, OUT> {
+	#[inline]
+	fn from(contract: TransmuteContract<IN, OUT>) -> Self {
+		Self::new(contract)
+	}
+}
+
+impl<IN, OUT> Debug for DropAsOut<IN, OUT>
+where
+	IN: Debug,
+{
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		Debug::fmt(self.contract(), f)
+	}
+}
+
+impl<IN, OUT> PartialEq for DropAsOut<IN, OUT>
+where
+	IN: PartialEq,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		PartialEq::eq(self.contract(), other.contract())
+	}
+}
+
+impl<IN, OUT> Eq for DropAsOut<IN, OUT> where IN: Eq {}
+
+impl<IN, OUT> Hash for DropAsOut<IN, OUT>
+where
+	IN: Hash,
+{
+	#[inline]
+	fn hash<H>(&self, h: &mut H)
+	where
+		H: Hasher,
+	{
+		Hash::hash(self.contract(), h)
+	}
+}
+
+/// Declares a safe newtype around [TransmuteContract].
+///
+/// ```rust
+/// use cluFullTransmute::declare_contract;
+///
+/// declare_contract! {
+/// 	/// `&'static str` that can always be read as `&'static [u8]`.
+/// 	#[derive(Debug, Clone, Copy)]
+/// 	pub unsafe struct MyData: &'static str => &'static [u8];
+/// }
+///
+/// const DATA: MyData = MyData::new("Test");
+///
+/// assert_eq!(DATA.as_in(), &"Test");
+/// assert_eq!(DATA.as_out(), &b"Test".as_slice());
+/// assert_eq!(DATA.into_out(), b"Test");
+/// ```
+///
+/// The declaration is the only place where the contract is signed: the
+/// generated `new` is safe, so by writing `unsafe struct Name: IN => OUT` the
+/// author of the declaration guarantees that every valid value of `IN` is also
+/// a valid value of `OUT`. The equality of the sizes of `IN` and `OUT` is
+/// checked at compile time.
+///
+/// A declaration without `unsafe` is rejected:
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::declare_contract;
+///
+/// declare_contract!(struct Bad: u8 => bool);
+/// ```
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::declare_contract;
+///
+/// declare_contract!(unsafe struct Bad: u32 => u64);
+/// ```
+#[macro_export]
+macro_rules! declare_contract {
+	[
+		$(#[$meta:meta])*
+		$vis:vis unsafe struct $name:ident : $in:ty => $out:ty $(;)?
+	] => {
+		$(#[$meta])*
+		#[repr(transparent)]
+		$vis struct $name {
+			data: $crate::contract::TransmuteContract<$in, $out>,
+		}
+
+		const _: () = ::core::assert!(
+			::core::mem::size_of::<$in>() == ::core::mem::size_of::<$out>(),
+			"declare_contract: the sizes of the input and output types must match exactly"
+		);
+
+		#[allow(dead_code)]
+		impl $name {
+			/// Create a new value, the data always satisfies the contract.
+			#[inline]
+			pub const fn new(data: $in) -> Self {
+				// The contract was signed by the author of the `unsafe struct`
+				// declaration, this is the only place where it is relied upon.
+				let data = unsafe { $crate::contract::TransmuteContract::new_unchecked(data) };
+
+				Self { data }
+			}
+
+			/// Get a link to the input data.
+			#[inline]
+			pub const fn as_in(&self) -> &$in {
+				self.data.as_in()
+			}
+
+			/// Getting a pseudo-pointer to the converted value without substitution.
+			#[inline]
+			pub const fn as_out(&self) -> &$out {
+				self.data.as_out()
+			}
+
+			/// Get a link to the contract.
+			#[inline]
+			pub const fn as_contract(&self) -> &$crate::contract::TransmuteContract<$in, $out> {
+				&self.data
+			}
+
+			/// Return the input data back.
+			#[inline]
+			pub const fn into_in(self) -> $in {
+				self.into_contract().release_indata()
+			}
+
+			/// Execute the contract and return a value with the new data type.
+			#[inline]
+			pub const fn into_out(self) -> $out {
+				self.into_contract().into()
+			}
+
+			/// Return the contract.
+			#[inline]
+			pub const fn into_contract(self) -> $crate::contract::TransmuteContract<$in, $out> {
+				let Self { data } = self;
+
+				data
+			}
+		}
+
+		impl ::core::ops::Deref for $name {
+			type Target = $in;
+
+			#[inline]
+			fn deref(&self) -> &Self::Target {
+				self.as_in()
+			}
+		}
+
+		impl ::core::convert::AsRef<$in> for $name {
+			#[inline]
+			fn as_ref(&self) -> &$in {
+				self.as_in()
+			}
+		}
+
+		impl ::core::borrow::Borrow<$in> for $name {
+			#[inline]
+			fn borrow(&self) -> &$in {
+				self.as_in()
+			}
+		}
+
+		impl ::core::convert::From<$in> for $name {
+			#[inline]
+			fn from(data: $in) -> Self {
+				Self::new(data)
+			}
+		}
+
+		impl ::core::convert::From<$name> for $out {
+			#[inline]
+			fn from(data: $name) -> Self {
+				data.into_out()
+			}
+		}
+	};
+}
@@ -0,0 +1,414 @@
+//! Endianness-aware conversions between byte arrays and arrays of numbers.
+//!
+//! ```rust
+//! use cluFullTransmute::endian;
+//!
+//! const PACKET: [u8; 8] = [0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x00, 0x00, 0x01];
+//! const VALUES: [u32; 2] = endian::from_be_bytes(PACKET);
+//!
+//! assert_eq!(VALUES, [0xDEADBEEF, 1]);
+//! assert_eq!(endian::to_be_bytes::<u32, 2, 8>(VALUES), PACKET);
+//! ```
+//!
+//! Integers with a fixed byte order (`U16Be`, `U32Le`, ...) have an alignment of 1,
+//! no padding and are valid for any bit pattern, so they can be used as fields of
+//! `#[repr(C)]` structs that are transmuted from byte buffers:
+//!
+//! ```rust
+//! use cluFullTransmute::bytes;
+//! use cluFullTransmute::bytes::AnyBitPattern;
+//! use cluFullTransmute::bytes::NoPadding;
+//! use cluFullTransmute::bytes::Zeroable;
+//! use cluFullTransmute::endian::U16Be;
+//! use cluFullTransmute::endian::U32Be;
+//!
+//! #[repr(C)]
+//! #[derive(Clone, Copy)]
+//! struct UdpHeader {
+//! 	src_port: U16Be,
+//! 	dst_port: U16Be,
+//! 	len: U16Be,
+//! 	checksum: U16Be,
+//! }
+//!
+//! unsafe impl NoPadding for UdpHeader {}
+//! unsafe impl AnyBitPattern for UdpHeader {}
+//! unsafe impl Zeroable for UdpHeader {}
+//!
+//! let packet: [u8; 8] = [0x1F, 0x90, 0x00, 0x35, 0x00, 0x08, 0x00, 0x00];
+//! let header: &UdpHeader = bytes::from_bytes(&packet).unwrap();
+//!
+//! assert_eq!(header.src_port.get(), 8080);
+//! assert_eq!(header.dst_port.get(), 53);
+//! ```
+
+use crate::bytes::AnyBitPattern;
+use crate::bytes::NoPadding;
+use crate::bytes::Zeroable;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+/// The order of bytes in memory.
+pub trait ByteOrder {
+	/// Whether the most significant byte comes first.
+	const IS_BIG_ENDIAN: bool;
+
+	/// Whether the byte order matches the byte order of the current platform.
+	const IS_NATIVE: bool = Self::IS_BIG_ENDIAN == cfg!(target_endian = "big");
+}
+
+/// The most significant byte comes first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BigEndian {}
+
+/// The least significant byte comes first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LittleEndian {}
+
+impl ByteOrder for BigEndian {
+	const IS_BIG_ENDIAN: bool = true;
+}
+
+impl ByteOrder for LittleEndian {
+	const IS_BIG_ENDIAN: bool = false;
+}
+
+/// The byte order of network protocols.
+pub type NetworkEndian = BigEndian;
+
+/// The byte order of the current platform.
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+/// The byte order of the current platform.
+#[cfg(not(target_endian = "big"))]
+pub type NativeEndian = LittleEndian;
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// Primitive integers and floats whose byte order can be changed by
+/// reversing their bytes.
+pub trait EndianScalar: NoPadding + AnyBitPattern + sealed::Sealed {}
+
+macro_rules! impl_endian_scalar {
+	[ $($t:ty),* $(,)? ] => {
+		$(
+			impl sealed::Sealed for $t {}
+			impl EndianScalar for $t {}
+		)*
+	};
+}
+
+impl_endian_scalar![
+	u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+];
+
+/// Reverses the bytes of every `size` bytes element of the array.
+const fn swap_elements<const B: usize>(mut bytes: [u8; B], size: usize) -> [u8; B] {
+	if size > 1 {
+		let mut i = 0;
+		while i + size <= B {
+			let (mut a, mut b) = (i, i + size - 1);
+			while a < b {
+				let tmp = bytes[a];
+				bytes[a] = bytes[b];
+				bytes[b] = tmp;
+
+				a += 1;
+				b -= 1;
+			}
+
+			i += size;
+		}
+	}
+
+	bytes
+}
+
+/// Converts bytes stored in the byte order `O` to an array of numbers.
+///
+/// On platforms with the byte order `O` it is a plain transmutation,
+/// otherwise the bytes of every number are swapped.
+///
+/// The function is completely constant, in case `B` is not equal to
+/// `N * size_of::<T>()`, a panic pops up.
+#[track_caller]
+#[inline]
+pub const fn from_bytes<O, T, const B: usize, const N: usize>(bytes: [u8; B]) -> [T; N]
+where
+	O: ByteOrder,
+	T: EndianScalar,
+{
+	let bytes = match O::IS_NATIVE {
+		true => bytes,
+		false => swap_elements(bytes, size_of::<T>()),
+	};
+
+	unsafe { crate::try_transmute_or_panic(bytes) }
+}
+
+/// Converts an array of numbers to bytes stored in the byte order `O`.
+///
+/// On platforms with the byte order `O` it is a plain transmutation,
+/// otherwise the bytes of every number are swapped.
+///
+/// The function is completely constant, in case `B` is not equal to
+/// `N * size_of::<T>()`, a panic pops up.
+#[track_caller]
+#[inline]
+pub const fn to_bytes<O, T, const N: usize, const B: usize>(values: [T; N]) -> [u8; B]
+where
+	O: ByteOrder,
+	T: EndianScalar,
+{
+	let bytes: [u8; B] = unsafe { crate::try_transmute_or_panic(values) };
+
+	match O::IS_NATIVE {
+		true => bytes,
+		false => swap_elements(bytes, size_of::<T>()),
+	}
+}
+
+/// Converts big-endian bytes to an array of numbers (e.g. `[u8; 4 * N] -> [u32; N]`).
+#[track_caller]
+#[inline]
+pub const fn from_be_bytes<T, const B: usize, const N: usize>(bytes: [u8; B]) -> [T; N]
+where
+	T: EndianScalar,
+{
+	from_bytes::<BigEndian, T, B, N>(bytes)
+}
+
+/// Converts little-endian bytes to an array of numbers (e.g. `[u8; 4 * N] -> [u32; N]`).
+#[track_caller]
+#[inline]
+pub const fn from_le_bytes<T, const B: usize, const N: usize>(bytes: [u8; B]) -> [T; N]
+where
+	T: EndianScalar,
+{
+	from_bytes::<LittleEndian, T, B, N>(bytes)
+}
+
+/// Converts an array of numbers to big-endian bytes (e.g. `[u32; N] -> [u8; 4 * N]`).
+#[track_caller]
+#[inline]
+pub const fn to_be_bytes<T, const N: usize, const B: usize>(values: [T; N]) -> [u8; B]
+where
+	T: EndianScalar,
+{
+	to_bytes::<BigEndian, T, N, B>(values)
+}
+
+/// Converts an array of numbers to little-endian bytes (e.g. `[u32; N] -> [u8; 4 * N]`).
+#[track_caller]
+#[inline]
+pub const fn to_le_bytes<T, const N: usize, const B: usize>(values: [T; N]) -> [u8; B]
+where
+	T: EndianScalar,
+{
+	to_bytes::<LittleEndian, T, N, B>(values)
+}
+
+macro_rules! endian_int {
+	[ $( $(#[$meta:meta])* $name:ident($t:ty): $be:ident, $le:ident; )* ] => {
+		$(
+			$(#[$meta])*
+			///
+			/// The value is stored as bytes in the byte order `O`, the type has an
+			/// alignment of 1, no padding and is valid for any bit pattern.
+			#[repr(transparent)]
+			pub struct $name<O>([u8; size_of::<$t>()], PhantomData<O>);
+
+			#[doc = concat!("[", stringify!($name), "] stored in big-endian byte order.")]
+			pub type $be = $name<BigEndian>;
+
+			#[doc = concat!("[", stringify!($name), "] stored in little-endian byte order.")]
+			pub type $le = $name<LittleEndian>;
+
+			impl<O> $name<O>
+			where
+				O: ByteOrder,
+			{
+				/// The value equal to zero.
+				pub const ZERO: Self = Self::from_bytes([0; size_of::<$t>()]);
+
+				/// Create a new value.
+				#[inline]
+				pub const fn new(value: $t) -> Self {
+					let bytes = match O::IS_BIG_ENDIAN {
+						true => value.to_be_bytes(),
+						false => value.to_le_bytes(),
+					};
+
+					Self::from_bytes(bytes)
+				}
+
+				/// Create a value from bytes stored in the byte order `O`.
+				#[inline]
+				pub const fn from_bytes(bytes: [u8; size_of::<$t>()]) -> Self {
+					Self(bytes, PhantomData)
+				}
+
+				/// Get the value in the native byte order.
+				#[inline]
+				pub const fn get(self) -> $t {
+					match O::IS_BIG_ENDIAN {
+						true => <$t>::from_be_bytes(self.0),
+						false => <$t>::from_le_bytes(self.0),
+					}
+				}
+
+				/// Replace the value.
+				#[inline]
+				pub const fn set(&mut self, value: $t) {
+					*self = Self::new(value);
+				}
+
+				/// Get the bytes stored in the byte order `O`.
+				#[inline]
+				pub const fn to_bytes(self) -> [u8; size_of::<$t>()] {
+					self.0
+				}
+			}
+
+			impl<O> Clone for $name<O> {
+				#[inline]
+				fn clone(&self) -> Self {
+					*self
+				}
+			}
+
+			impl<O> Copy for $name<O> {}
+
+			impl<O> Default for $name<O>
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn default() -> Self {
+					Self::ZERO
+				}
+			}
+
+			impl<O> From<$t> for $name<O>
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn from(value: $t) -> Self {
+					Self::new(value)
+				}
+			}
+
+			impl<O> From<$name<O>> for $t
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn from(value: $name<O>) -> Self {
+					value.get()
+				}
+			}
+
+			impl<O> Debug for $name<O>
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+					Debug::fmt(&self.get(), f)
+				}
+			}
+
+			impl<O> Display for $name<O>
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+					Display::fmt(&self.get(), f)
+				}
+			}
+
+			impl<O> PartialEq for $name<O> {
+				#[inline]
+				fn eq(&self, other: &Self) -> bool {
+					PartialEq::eq(&self.0, &other.0)
+				}
+			}
+
+			impl<O> Eq for $name<O> {}
+
+			impl<O> PartialEq<$t> for $name<O>
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn eq(&self, other: &$t) -> bool {
+					PartialEq::eq(&self.get(), other)
+				}
+			}
+
+			impl<O> PartialOrd for $name<O>
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn partial_cmp(&self, o: &Self) -> Option<Ordering> {
+					Some(Ord::cmp(self, o))
+				}
+			}
+
+			impl<O> Ord for $name<O>
+			where
+				O: ByteOrder,
+			{
+				#[inline]
+				fn cmp(&self, c: &Self) -> Ordering {
+					Ord::cmp(&self.get(), &c.get())
+				}
+			}
+
+			impl<O> Hash for $name<O> {
+				#[inline]
+				fn hash<H>(&self, h: &mut H)
+				where
+					H: Hasher,
+				{
+					Hash::hash(&self.0, h)
+				}
+			}
+
+			unsafe impl<O> NoPadding for $name<O> where O: 'static {}
+			unsafe impl<O> AnyBitPattern for $name<O> where O: 'static {}
+			unsafe impl<O> Zeroable for $name<O> {}
+		)*
+	};
+}
+
+endian_int! [
+	/// `u16` with a fixed byte order.
+	U16(u16): U16Be, U16Le;
+	/// `u32` with a fixed byte order.
+	U32(u32): U32Be, U32Le;
+	/// `u64` with a fixed byte order.
+	U64(u64): U64Be, U64Le;
+	/// `u128` with a fixed byte order.
+	U128(u128): U128Be, U128Le;
+	/// `i16` with a fixed byte order.
+	I16(i16): I16Be, I16Le;
+	/// `i32` with a fixed byte order.
+	I32(i32): I32Be, I32Le;
+	/// `i64` with a fixed byte order.
+	I64(i64): I64Be, I64Le;
+	/// `i128` with a fixed byte order.
+	I128(i128): I128Be, I128Le;
+];
@@ -0,0 +1,315 @@
+//! Inline type-erased storage for small values, without allocation.
+//!
+//! ```rust
+//! use cluFullTransmute::erased::ErasedValue;
+//!
+//! // 16 bytes, aligned as `u64`
+//! type Message = ErasedValue<16, u64>;
+//!
+//! let queue: [Message; 2] = [Message::new(10u32), Message::new((1u64, 'a'))];
+//!
+//! let [a, b] = queue;
+//! assert!(a.is::<u32>());
+//! assert_eq!(a.downcast::<u32>().ok(), Some(10));
+//! assert!(b.downcast::<u32>().is_err());
+//! ```
+//!
+//! `ErasedValue` is not `Send`, to pass values between threads use
+//! [SendErasedValue], which only accepts `Send` values.
+
+use crate::err::TransmuteErr;
+use crate::raw::TransmutData;
+use core::any::TypeId;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::mem::MaybeUninit;
+use core::mem::align_of;
+use core::mem::size_of;
+
+/// `SIZE` bytes aligned as `A`.
+#[repr(C)]
+struct ErasedData<const SIZE: usize, A> {
+	_align: [A; 0],
+	bytes: [MaybeUninit<u8>; SIZE],
+}
+
+/// Drops the `T` stored at the pointer.
+unsafe fn drop_erased<T>(ptr: *mut u8) {
+	unsafe { core::ptr::drop_in_place(ptr as *mut T) }
+}
+
+/// Checking that `T` fits into `ErasedValue<SIZE, A>` at compile time
+struct FitCheck<T, const SIZE: usize, A>(PhantomData<(T, A)>);
+
+impl<T, const SIZE: usize, A> FitCheck<T, SIZE, A> {
+	const TYPE_FIT_ASSERT: () = [()][
+		// If you read this in the error logs, then the value is larger than
+		// `SIZE` or more aligned than `A`.
+		(size_of::<T>() > SIZE || align_of::<T>() > align_of::<A>()) as usize
+	];
+}
+
+/// A value of any `'static` type, which fits into `SIZE` bytes aligned as `A`,
+/// stored inline with its `TypeId` and drop function.
+///
+/// The value is dropped together with the container, unless it is taken out
+/// by `downcast`.
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::erased::ErasedValue;
+///
+/// let value = ErasedValue::<4, u32>::new(0u64);
+/// ```
+pub struct ErasedValue<const SIZE: usize, A = usize> {
+	data: ErasedData<SIZE, A>,
+	type_id: TypeId,
+	drop: unsafe fn(*mut u8),
+
+	/// The type of the value is unknown, so it is neither `Send` nor `Sync`.
+	_pp: PhantomData<*mut ()>,
+}
+
+impl<const SIZE: usize, A> ErasedValue<SIZE, A> {
+	/// Store the value, a value that does not fit into `SIZE` bytes aligned as
+	/// `A` is rejected at compile time.
+	#[inline]
+	pub fn new<T>(value: T) -> Self
+	where
+		T: 'static,
+	{
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_value_fit = FitCheck::<T, SIZE, A>::TYPE_FIT_ASSERT;
+
+		unsafe { Self::new_unchecked(value) }
+	}
+
+	/// Store the value, if the value does not fit into `SIZE` bytes aligned
+	/// as `A`, an error occurs.
+	#[inline]
+	pub fn try_new<T>(value: T) -> Result<Self, TransmuteErr<T>>
+	where
+		T: 'static,
+	{
+		{
+			// Data dimension check
+			let size_d = SIZE;
+			let size_to = size_of::<T>();
+			if size_to > size_d {
+				return Err(TransmuteErr::size_exceeded(size_d, size_to, value));
+			}
+		}
+		{
+			// Data alignment check
+			let align_d = align_of::<A>();
+			let align_to = align_of::<T>();
+			if align_to > align_d {
+				return Err(TransmuteErr::align_mismatch(align_d, align_to, value));
+			}
+		}
+
+		Ok(unsafe { Self::new_unchecked(value) })
+	}
+
+	/// # Safety
+	///
+	/// The value must fit into `SIZE` bytes aligned as `A`.
+	#[inline]
+	unsafe fn new_unchecked<T>(value: T) -> Self
+	where
+		T: 'static,
+	{
+		let data = TransmutData::<T, ErasedData<SIZE, A>> {
+			r#in: ManuallyDrop::new(value),
+		};
+
+		Self {
+			// The bytes after the value remain uninitialized (`MaybeUninit<u8>`).
+			data: ManuallyDrop::into_inner(unsafe { data.out }),
+			type_id: TypeId::of::<T>(),
+			drop: drop_erased::<T>,
+			_pp: PhantomData,
+		}
+	}
+
+	/// The `TypeId` of the stored value.
+	#[inline]
+	pub const fn type_id(&self) -> TypeId {
+		self.type_id
+	}
+
+	/// Whether the stored value is of type `T`.
+	#[inline]
+	pub fn is<T>(&self) -> bool
+	where
+		T: 'static,
+	{
+		self.type_id == TypeId::of::<T>()
+	}
+
+	/// Take the value if it is of type `T`, otherwise the container is returned back.
+	#[inline]
+	pub fn downcast<T>(self) -> Result<T, Self>
+	where
+		T: 'static,
+	{
+		if !self.is::<T>() {
+			return Err(self);
+		}
+
+		let this = ManuallyDrop::new(self);
+		let data = TransmutData::<T, ErasedData<SIZE, A>> {
+			out: ManuallyDrop::new(unsafe { core::ptr::read(&this.data) }),
+		};
+
+		Ok(ManuallyDrop::into_inner(unsafe { data.r#in }))
+	}
+
+	/// Get a reference to the value if it is of type `T`.
+	#[inline]
+	pub fn downcast_ref<T>(&self) -> Option<&T>
+	where
+		T: 'static,
+	{
+		match self.is::<T>() {
+			true => Some(unsafe { &*(self.data.bytes.as_ptr() as *const T) }),
+			false => None,
+		}
+	}
+
+	/// Get a mutable reference to the value if it is of type `T`.
+	#[inline]
+	pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+	where
+		T: 'static,
+	{
+		match self.is::<T>() {
+			true => Some(unsafe { &mut *(self.data.bytes.as_mut_ptr() as *mut T) }),
+			false => None,
+		}
+	}
+}
+
+impl<const SIZE: usize, A> Drop for ErasedValue<SIZE, A> {
+	#[inline]
+	fn drop(&mut self) {
+		unsafe { (self.drop)(self.data.bytes.as_mut_ptr() as *mut u8) }
+	}
+}
+
+impl<const SIZE: usize, A> Debug for ErasedValue<SIZE, A> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		f.debug_struct("ErasedValue")
+			.field("type_id", &self.type_id)
+			.finish_non_exhaustive()
+	}
+}
+
+/// An [ErasedValue] that only accepts `Send` values, so it is `Send` itself
+/// (e.g. for a message queue between threads).
+///
+/// ```rust
+/// use cluFullTransmute::erased::SendErasedValue;
+///
+/// let value = SendErasedValue::<32, u64>::new(String::from("Test"));
+/// let value = std::thread::spawn(move || value.downcast::<String>().ok())
+/// 	.join()
+/// 	.unwrap();
+/// assert_eq!(value.as_deref(), Some("Test"));
+/// ```
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::erased::SendErasedValue;
+///
+/// let value = SendErasedValue::<16>::new(std::rc::Rc::new(0));
+/// ```
+pub struct SendErasedValue<const SIZE: usize, A = usize>(ErasedValue<SIZE, A>);
+
+/// All the values are `Send` (checked by the constructors), `A` is only used
+/// for the alignment.
+unsafe impl<const SIZE: usize, A> Send for SendErasedValue<SIZE, A> {}
+
+impl<const SIZE: usize, A> SendErasedValue<SIZE, A> {
+	/// Store the value, a value that does not fit into `SIZE` bytes aligned as
+	/// `A` is rejected at compile time.
+	#[inline]
+	pub fn new<T>(value: T) -> Self
+	where
+		T: Send + 'static,
+	{
+		Self(ErasedValue::new(value))
+	}
+
+	/// Store the value, if the value does not fit into `SIZE` bytes aligned
+	/// as `A`, an error occurs.
+	#[inline]
+	pub fn try_new<T>(value: T) -> Result<Self, TransmuteErr<T>>
+	where
+		T: Send + 'static,
+	{
+		match ErasedValue::try_new(value) {
+			Ok(a) => Ok(Self(a)),
+			Err(e) => Err(e),
+		}
+	}
+
+	/// The `TypeId` of the stored value.
+	#[inline]
+	pub const fn type_id(&self) -> TypeId {
+		self.0.type_id()
+	}
+
+	/// Whether the stored value is of type `T`.
+	#[inline]
+	pub fn is<T>(&self) -> bool
+	where
+		T: 'static,
+	{
+		self.0.is::<T>()
+	}
+
+	/// Take the value if it is of type `T`, otherwise the container is returned back.
+	#[inline]
+	pub fn downcast<T>(self) -> Result<T, Self>
+	where
+		T: 'static,
+	{
+		match self.0.downcast::<T>() {
+			Ok(a) => Ok(a),
+			Err(e) => Err(Self(e)),
+		}
+	}
+
+	/// Get a reference to the value if it is of type `T`.
+	#[inline]
+	pub fn downcast_ref<T>(&self) -> Option<&T>
+	where
+		T: 'static,
+	{
+		self.0.downcast_ref::<T>()
+	}
+
+	/// Get a mutable reference to the value if it is of type `T`.
+	#[inline]
+	pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+	where
+		T: 'static,
+	{
+		self.0.downcast_mut::<T>()
+	}
+
+	/// Convert into a plain `ErasedValue` (which is not `Send`).
+	#[inline]
+	pub fn into_erased(self) -> ErasedValue<SIZE, A> {
+		self.0
+	}
+}
+
+impl<const SIZE: usize, A> Debug for SendErasedValue<SIZE, A> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		f.debug_struct("SendErasedValue")
+			.field("type_id", &self.0.type_id)
+			.finish_non_exhaustive()
+	}
+}
@@ -7,9 +7,10 @@ use core::ops::Deref;
 
 /// Error structure and error type with a detailed description of the cause.
 ///
-/// (Note that the `stderr` build flag includes std and
+/// (Note that the `std` (or `stderr`) build flag includes std and
 /// implements std::error::Error for the given error.)
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransmuteErr<T> {
 	/// The data involved in creating the transmutation.
 	data: T,
@@ -19,7 +20,11 @@ pub struct TransmuteErr<T> {
 }
 
 /// Reason for getting the error.
+///
+/// (new reasons may be added in minor versions, so the enum is `#[non_exhaustive]`)
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum TransmuteErrKind {
 	/// Mismatch in input/output type sizes (e.g. `size_of::<A>() != size_of::<B>()`)
 	SizeMismatch { atype: usize, btype: usize },
@@ -28,6 +33,20 @@ pub enum TransmuteErrKind {
 	/// in debug_assertions.
 	#[cfg(all(feature = "assert_transmute_mode", debug_assertions))]
 	SizeMismatchInDebugAssert { atype: usize, btype: usize },
+
+	/// The output type is larger than the input type (e.g. `size_of::<B>() > size_of::<A>()`)
+	SizeExceeded { atype: usize, btype: usize },
+
+	/// The alignment of the output type is not satisfied by the input
+	/// (e.g. `align_of::<B>() > align_of::<A>()`)
+	AlignMismatch { atype: usize, btype: usize },
+
+	/// The layout fingerprint stored with the data does not match the layout
+	/// fingerprint of the output type (e.g. `stored != B::LAYOUT_FINGERPRINT`)
+	LayoutMismatch { atype: u64, btype: u64 },
+
+	/// The data is not valid UTF-8 (e.g. `&[u8]` as `&str`)
+	InvalidUtf8 { valid_up_to: usize },
 }
 
 impl TransmuteErrKind {
@@ -46,6 +65,33 @@ impl TransmuteErrKind {
 		Self::SizeMismatchInDebugAssert { atype, btype }
 	}
 
+	/// The size of the output type is larger than the size of the input
+	/// (sizeB is larger than sizeA).
+	#[inline]
+	pub const fn size_exceeded(atype: usize, btype: usize) -> Self {
+		Self::SizeExceeded { atype, btype }
+	}
+
+	/// The alignment of the output type is not satisfied by the input
+	/// (alignB is larger than alignA).
+	#[inline]
+	pub const fn align_mismatch(atype: usize, btype: usize) -> Self {
+		Self::AlignMismatch { atype, btype }
+	}
+
+	/// The layout fingerprint stored with the data does not match the layout
+	/// fingerprint of the output type (fingerprintA is not equal to fingerprintB).
+	#[inline]
+	pub const fn layout_mismatch(atype: u64, btype: u64) -> Self {
+		Self::LayoutMismatch { atype, btype }
+	}
+
+	/// The data is not valid UTF-8, only the first `valid_up_to` bytes are valid.
+	#[inline]
+	pub const fn invalid_utf8(valid_up_to: usize) -> Self {
+		Self::InvalidUtf8 { valid_up_to }
+	}
+
 	/// Whether the current cause of the error is related to the inequality
 	/// of data dimensions at the input and output.
 	#[inline]
@@ -60,6 +106,33 @@ impl TransmuteErrKind {
 		matches!(self, Self::SizeMismatch { .. })
 	}
 
+	/// Whether the current cause of the error is related to the output type
+	/// being larger than the input.
+	#[inline]
+	pub const fn is_size_exceeded(&self) -> bool {
+		matches!(self, Self::SizeExceeded { .. })
+	}
+
+	/// Whether the current cause of the error is related to the alignment
+	/// of data at the input and output.
+	#[inline]
+	pub const fn is_align_mismatch(&self) -> bool {
+		matches!(self, Self::AlignMismatch { .. })
+	}
+
+	/// Whether the current cause of the error is related to the changed
+	/// layout of the output type.
+	#[inline]
+	pub const fn is_layout_mismatch(&self) -> bool {
+		matches!(self, Self::LayoutMismatch { .. })
+	}
+
+	/// Whether the current cause of the error is related to invalid UTF-8 data.
+	#[inline]
+	pub const fn is_invalid_utf8(&self) -> bool {
+		matches!(self, Self::InvalidUtf8 { .. })
+	}
+
 	/// Creates a formatted error description in const mode.
 	#[inline]
 	pub const fn as_description(&self) -> DescriptionOut {
@@ -84,6 +157,14 @@ impl TransmuteErrKind {
 	}
 }
 
+impl Display for TransmuteErrKind {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		let description = self.as_description();
+
+		Display::fmt(description.as_str(), f)
+	}
+}
+
 impl<T> Display for TransmuteErr<T> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
 		let description = self.as_description();
@@ -105,6 +186,33 @@ impl<T> TransmuteErr<T> {
 		Self::new(TransmuteErrKind::size_mismatch(sizea, sizeb), data)
 	}
 
+	/// Quickly create a bug with a predefined reason for output type larger than input type.
+	#[inline]
+	pub const fn size_exceeded(sizea: usize, sizeb: usize, data: T) -> Self {
+		Self::new(TransmuteErrKind::size_exceeded(sizea, sizeb), data)
+	}
+
+	/// Quickly create a bug with a predefined reason for unsatisfied output type alignment.
+	#[inline]
+	pub const fn align_mismatch(aligna: usize, alignb: usize, data: T) -> Self {
+		Self::new(TransmuteErrKind::align_mismatch(aligna, alignb), data)
+	}
+
+	/// Quickly create a bug with a predefined reason for unequal layout fingerprints.
+	#[inline]
+	pub const fn layout_mismatch(fingerprinta: u64, fingerprintb: u64, data: T) -> Self {
+		Self::new(
+			TransmuteErrKind::layout_mismatch(fingerprinta, fingerprintb),
+			data,
+		)
+	}
+
+	/// Quickly create a bug with a predefined reason for invalid UTF-8 data.
+	#[inline]
+	pub const fn invalid_utf8(valid_up_to: usize, data: T) -> Self {
+		Self::new(TransmuteErrKind::invalid_utf8(valid_up_to), data)
+	}
+
 	/// Always panics in const mode, this feature will be added in the future.
 	#[inline]
 	#[track_caller]
@@ -152,8 +260,8 @@ impl<T> Deref for TransmuteErr<T> {
 	}
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "stderr")))]
-#[cfg(feature = "stderr")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
 mod stderr {
 	use crate::err::TransmuteErr;
 	use crate::err::TransmuteErrKind;
@@ -167,7 +275,14 @@ mod stderr {
 		#[allow(deprecated)]
 		#[inline]
 		fn description(&self) -> &str {
-			match self.kind {
+			Error::description(&self.kind)
+		}
+	}
+
+	impl Error for TransmuteErrKind {
+		#[inline]
+		fn description(&self) -> &str {
+			match self {
 				TransmuteErrKind::SizeMismatch { .. } => {
 					"TransmuteErrKind::SizeMismatch(atype != bsize)"
 				}
@@ -175,13 +290,23 @@ mod stderr {
 				TransmuteErrKind::SizeMismatchInDebugAssert { .. } => {
 					"TransmuteErrKind::SizeMismatch(atype != bsize)"
 				}
+				TransmuteErrKind::SizeExceeded { .. } => {
+					"TransmuteErrKind::SizeExceeded(asize < bsize)"
+				}
+				TransmuteErrKind::AlignMismatch { .. } => {
+					"TransmuteErrKind::AlignMismatch(aalign < balign)"
+				}
+				TransmuteErrKind::LayoutMismatch { .. } => {
+					"TransmuteErrKind::LayoutMismatch(afingerprint != bfingerprint)"
+				}
+				TransmuteErrKind::InvalidUtf8 { .. } => "TransmuteErrKind::InvalidUtf8",
 			}
 		}
 	}
 }
 
 #[allow(unused_imports)]
-#[cfg(feature = "stderr")]
+#[cfg(feature = "std")]
 pub use stderr::*;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "error_details")))]
@@ -193,11 +318,23 @@ mod error_details {
 
 	pub type DescriptionOut = ConstStrBuf<{ CAPACITY }>;
 
-	const CAPACITY: usize = DESCRIPTION_S0.len() // str
+	const CAPACITY: usize = max(max(CAPACITY_SIZE, CAPACITY_LAYOUT), CAPACITY_UTF8);
+	const CAPACITY_UTF8: usize = DESCRIPTION_UTF8_S0.len() // str
+				+ usize::MAX_DECIMAL_LEN // usize
+				+ DESCRIPTION_UTF8_S1.len(); // str
+	const CAPACITY_LAYOUT: usize = DESCRIPTION_LAYOUT_S0.len() // str
+				+ FINGERPRINT_LEN // u64
+				+ DESCRIPTION_LAYOUT_S1.len() // str
+				+ FINGERPRINT_LEN // u64
+				+ DESCRIPTION_LAYOUT_S2.len(); // str
+	const CAPACITY_SIZE: usize = max(DESCRIPTION_S0.len(), DESCRIPTION_ALIGN_S0.len()) // str
 				+ usize::MAX_DECIMAL_LEN // usize
-				+ DESCRIPTION_S1.len() // str
+				+ max(DESCRIPTION_S1.len(), DESCRIPTION_ALIGN_S1.len()) // str
 				+ usize::MAX_DECIMAL_LEN // usize
-				+ DESCRIPTION_S2.len() + { // str
+				+ max(
+					max(DESCRIPTION_S2.len(), DESCRIPTION_EXCEEDED_S2.len()),
+					DESCRIPTION_ALIGN_S2.len()
+				) + { // str
 					#[cfg(all(feature = "assert_transmute_mode", debug_assertions))]
 					{DESCRIPTION_S3.len()}
 					#[cfg(not(all(feature = "assert_transmute_mode", debug_assertions)))]
@@ -206,27 +343,107 @@ mod error_details {
 	const DESCRIPTION_S0: &str = "Invalid transmute: attempted to reinterpret type A (";
 	const DESCRIPTION_S1: &str = " bytes) as incompatible type B (";
 	const DESCRIPTION_S2: &str = " bytes). Sizes must match exactly.";
+	const DESCRIPTION_EXCEEDED_S2: &str = " bytes). Type B must not be larger than type A.";
+	const DESCRIPTION_ALIGN_S0: &str =
+		"Invalid transmute: attempted to reinterpret data with alignment ";
+	const DESCRIPTION_ALIGN_S1: &str = " as incompatible type B with alignment ";
+	const DESCRIPTION_ALIGN_S2: &str =
+		". The alignment of type B must not be larger than the alignment of the data.";
+
+	const DESCRIPTION_LAYOUT_S0: &str = "Invalid transmute: the stored layout fingerprint (";
+	const DESCRIPTION_LAYOUT_S1: &str = ") does not match the layout fingerprint of type B (";
+	const DESCRIPTION_LAYOUT_S2: &str =
+		"). The layout of type B has changed since the data was written.";
+	const DESCRIPTION_UTF8_S0: &str =
+		"Invalid transmute: the data is not valid UTF-8 (valid up to ";
+	const DESCRIPTION_UTF8_S1: &str = " bytes).";
+	/// `0x` + 16 hex digits
+	const FINGERPRINT_LEN: usize = 2 + 16;
+
+	const fn max(a: usize, b: usize) -> usize {
+		if a > b { a } else { b }
+	}
 
 	#[cfg(all(feature = "assert_transmute_mode", debug_assertions))]
 	const DESCRIPTION_S3: &str = "This check was added additionally due to the inclusion of `debug_assertions` and the `assert_transmute_mode` function in `cluFullTransmute`.";
 	/// Creates a formatted error description in const mode.
 	pub(crate) const fn as_description(kind: TransmuteErrKind) -> DescriptionOut {
-		let (a_size, b_size, adebug_assert) = match kind {
-			TransmuteErrKind::SizeMismatch { atype, btype } => (atype, btype, false),
+		if let TransmuteErrKind::LayoutMismatch { atype, btype } = kind {
+			let mut buf = ConstStrBuf::new();
+			//
+			// format!(
+			//	{DESCRIPTION_LAYOUT_S0} {atype:#018x} {DESCRIPTION_LAYOUT_S1} {btype:#018x} {DESCRIPTION_LAYOUT_S2}
+			//)
+			buf.push_str(DESCRIPTION_LAYOUT_S0);
+			push_fingerprint(&mut buf, atype);
+			buf.push_str(DESCRIPTION_LAYOUT_S1);
+			push_fingerprint(&mut buf, btype);
+			buf.push_str(DESCRIPTION_LAYOUT_S2);
+
+			return buf;
+		}
+		if let TransmuteErrKind::InvalidUtf8 { valid_up_to } = kind {
+			let mut buf = ConstStrBuf::new();
+			//
+			// format!(
+			//	{DESCRIPTION_UTF8_S0} {valid_up_to} {DESCRIPTION_UTF8_S1}
+			//)
+			buf.push_str(DESCRIPTION_UTF8_S0);
+			buf.push_usize(valid_up_to);
+			buf.push_str(DESCRIPTION_UTF8_S1);
+
+			return buf;
+		}
+
+		let (s0, a_size, s1, b_size, s2, adebug_assert) = match kind {
+			TransmuteErrKind::SizeMismatch { atype, btype } => (
+				DESCRIPTION_S0,
+				atype,
+				DESCRIPTION_S1,
+				btype,
+				DESCRIPTION_S2,
+				false,
+			),
 			#[cfg(all(feature = "assert_transmute_mode", debug_assertions))]
-			TransmuteErrKind::SizeMismatchInDebugAssert { atype, btype } => (atype, btype, true),
+			TransmuteErrKind::SizeMismatchInDebugAssert { atype, btype } => (
+				DESCRIPTION_S0,
+				atype,
+				DESCRIPTION_S1,
+				btype,
+				DESCRIPTION_S2,
+				true,
+			),
+			TransmuteErrKind::SizeExceeded { atype, btype } => (
+				DESCRIPTION_S0,
+				atype,
+				DESCRIPTION_S1,
+				btype,
+				DESCRIPTION_EXCEEDED_S2,
+				false,
+			),
+			TransmuteErrKind::AlignMismatch { atype, btype } => (
+				DESCRIPTION_ALIGN_S0,
+				atype,
+				DESCRIPTION_ALIGN_S1,
+				btype,
+				DESCRIPTION_ALIGN_S2,
+				false,
+			),
+			TransmuteErrKind::LayoutMismatch { .. } | TransmuteErrKind::InvalidUtf8 { .. } => {
+				unreachable!()
+			}
 		};
 
 		let mut buf = ConstStrBuf::new();
 		//
 		// format!(
-		//	{DESCRIPTION_S0} {a_size} {DESCRIPTION_S1} {b_size} {DESCRIPTION_S2}
+		//	{s0} {a_size} {s1} {b_size} {s2}
 		//)
-		buf.push_str(DESCRIPTION_S0);
+		buf.push_str(s0);
 		buf.push_usize(a_size);
-		buf.push_str(DESCRIPTION_S1);
+		buf.push_str(s1);
 		buf.push_usize(b_size);
-		buf.push_str(DESCRIPTION_S2);
+		buf.push_str(s2);
 
 		if adebug_assert {
 			#[cfg(all(feature = "assert_transmute_mode", debug_assertions))]
@@ -236,6 +453,18 @@ mod error_details {
 
 		buf
 	}
+
+	/// Writes the fingerprint as `0x` and 16 hex digits.
+	const fn push_fingerprint(buf: &mut DescriptionOut, value: u64) {
+		const HEX: &[u8; 16] = b"0123456789abcdef";
+
+		buf.push_str("0x");
+		let mut shift = 64;
+		while shift > 0 {
+			shift -= 4;
+			buf.push_char(HEX[((value >> shift) & 0xf) as usize] as char);
+		}
+	}
 }
 
 #[cfg_attr(docsrs, doc(cfg(not(feature = "error_details"))))]
@@ -287,6 +516,16 @@ mod error_details {
 			TransmuteErrKind::SizeMismatchInDebugAssert { .. } => {
 				Str::new("TransmuteErrKind::SizeMismatchInDebugAssert(asize != bsize)")
 			}
+			TransmuteErrKind::SizeExceeded { .. } => {
+				Str::new("TransmuteErrKind::SizeExceeded(asize < bsize)")
+			}
+			TransmuteErrKind::AlignMismatch { .. } => {
+				Str::new("TransmuteErrKind::AlignMismatch(aalign < balign)")
+			}
+			TransmuteErrKind::LayoutMismatch { .. } => {
+				Str::new("TransmuteErrKind::LayoutMismatch(afingerprint != bfingerprint)")
+			}
+			TransmuteErrKind::InvalidUtf8 { .. } => Str::new("TransmuteErrKind::InvalidUtf8"),
 		}
 	}
 }
@@ -0,0 +1,75 @@
+//! Reading and writing values of fixed-layout types directly from `std::io` streams.
+//!
+//! ```rust
+//! use cluFullTransmute::io;
+//!
+//! let mut file: &[u8] = &[1, 0, 0, 0, 2, 0, 3, 0];
+//!
+//! let magic: u32 = io::read_transmuted(&mut file).unwrap();
+//! let mut values: [u16; 2] = [0; 2];
+//! io::read_slice_into(&mut file, &mut values).unwrap();
+//!
+//! assert_eq!(magic, u32::from_ne_bytes([1, 0, 0, 0]));
+//! assert_eq!(values, [u16::from_ne_bytes([2, 0]), u16::from_ne_bytes([3, 0])]);
+//! ```
+
+use crate::bytes::AnyBitPattern;
+use crate::bytes::NoPadding;
+use crate::err::TransmuteErr;
+use crate::err::TransmuteErrKind;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Result;
+use std::io::Write;
+
+/// Reads exactly `size_of::<T>()` bytes from the reader as a value of `T`.
+#[inline]
+pub fn read_transmuted<T, R>(reader: &mut R) -> Result<T>
+where
+	T: AnyBitPattern + NoPadding,
+	R: Read + ?Sized,
+{
+	// `T` is valid for any bit pattern, including all-zero.
+	let mut value: T = unsafe { core::mem::zeroed() };
+	reader.read_exact(crate::bytes::as_bytes_mut(&mut value))?;
+
+	Ok(value)
+}
+
+/// Reads exactly `size_of_val(values)` bytes from the reader into the slice.
+#[inline]
+pub fn read_slice_into<T, R>(reader: &mut R, values: &mut [T]) -> Result<()>
+where
+	T: AnyBitPattern + NoPadding,
+	R: Read + ?Sized,
+{
+	reader.read_exact(crate::bytes::slice_as_bytes_mut(values))
+}
+
+/// Writes all bytes of the value to the writer.
+#[inline]
+pub fn write_transmuted<T, W>(writer: &mut W, value: &T) -> Result<()>
+where
+	T: NoPadding,
+	W: Write + ?Sized,
+{
+	writer.write_all(crate::bytes::as_bytes(value))
+}
+
+/// The error of the transmutation is mapped to `ErrorKind::InvalidData`,
+/// the reason (`TransmuteErrKind`) is kept as the inner error.
+impl<T> From<TransmuteErr<T>> for Error {
+	#[inline]
+	fn from(e: TransmuteErr<T>) -> Self {
+		Error::new(ErrorKind::InvalidData, e.into_kind())
+	}
+}
+
+/// The reason of the transmutation error is mapped to `ErrorKind::InvalidData`.
+impl From<TransmuteErrKind> for Error {
+	#[inline]
+	fn from(e: TransmuteErrKind) -> Self {
+		Error::new(ErrorKind::InvalidData, e)
+	}
+}
@@ -0,0 +1,339 @@
+//! Layout fingerprints of types and the `Versioned` header for persisted data.
+//!
+//! The fingerprint changes if the layout of the type changes (field order,
+//! offsets, sizes, alignments, names or types of the fields), so data written
+//! by an older build is rejected instead of being silently reinterpreted.
+//!
+//! ```rust
+//! use cluFullTransmute::bytes::AnyBitPattern;
+//! use cluFullTransmute::bytes::NoPadding;
+//! use cluFullTransmute::bytes::Zeroable;
+//! use cluFullTransmute::layout::FingerprintHasher;
+//! use cluFullTransmute::layout::LayoutFingerprint;
+//! use cluFullTransmute::layout::Versioned;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Record {
+//! 	id: u32,
+//! 	value: u32,
+//! }
+//!
+//! unsafe impl NoPadding for Record {}
+//! unsafe impl AnyBitPattern for Record {}
+//! unsafe impl Zeroable for Record {}
+//!
+//! // (or `#[derive(LayoutFingerprint)]` with the `derive` build flag)
+//! impl LayoutFingerprint for Record {
+//! 	const LAYOUT_FINGERPRINT: u64 = FingerprintHasher::new()
+//! 		.write_field::<u32>("id", core::mem::offset_of!(Record, id), "u32")
+//! 		.write_field::<u32>("value", core::mem::offset_of!(Record, value), "u32")
+//! 		.finish();
+//! }
+//!
+//! let data = Versioned::new(Record { id: 1, value: 2 });
+//! let bytes: &[u8] = data.as_bytes();
+//!
+//! let record: Record = Versioned::<Record>::read_from(bytes).unwrap();
+//! assert_eq!(record, Record { id: 1, value: 2 });
+//! ```
+
+use crate::bytes::AnyBitPattern;
+use crate::bytes::FNV_OFFSET_BASIS;
+use crate::bytes::NoPadding;
+use crate::bytes::fnv1a;
+use crate::err::TransmuteErr;
+use crate::err::TransmuteErrKind;
+use core::fmt::Debug;
+use core::mem::align_of;
+use core::mem::size_of;
+
+/// Calculates `LayoutFingerprint` for a struct from the names, offsets,
+/// sizes, alignments, type names and fingerprints of its fields (the type of
+/// each field must implement `LayoutFingerprint`).
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use cluFullTransmute_derive::LayoutFingerprint;
+
+/// A type with a constant fingerprint of its layout.
+pub trait LayoutFingerprint {
+	/// A hash of the layout of the type.
+	const LAYOUT_FINGERPRINT: u64;
+}
+
+/// A constant hasher (FNV-1a 64) for calculating layout fingerprints.
+///
+/// All numbers are written as `u64` in little-endian order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FingerprintHasher(u64);
+
+impl Default for FingerprintHasher {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl FingerprintHasher {
+	/// Create a new hasher.
+	#[inline]
+	pub const fn new() -> Self {
+		Self(FNV_OFFSET_BASIS)
+	}
+
+	/// Write bytes.
+	#[inline]
+	pub const fn write_bytes(self, bytes: &[u8]) -> Self {
+		Self(fnv1a(self.0, bytes))
+	}
+
+	/// Write a string with its length.
+	#[inline]
+	pub const fn write_str(self, s: &str) -> Self {
+		self.write_usize(s.len()).write_bytes(s.as_bytes())
+	}
+
+	/// Write a number.
+	#[inline]
+	pub const fn write_u64(self, value: u64) -> Self {
+		self.write_bytes(&value.to_le_bytes())
+	}
+
+	/// Write a number.
+	#[inline]
+	pub const fn write_usize(self, value: usize) -> Self {
+		self.write_u64(value as u64)
+	}
+
+	/// Write the name, offset, size, alignment and type name of a field of type `T`.
+	#[inline]
+	pub const fn write_field<T>(self, name: &str, offset: usize, type_name: &str) -> Self {
+		self.write_str(name)
+			.write_usize(offset)
+			.write_usize(size_of::<T>())
+			.write_usize(align_of::<T>())
+			.write_str(type_name)
+	}
+
+	/// Get the fingerprint.
+	#[inline]
+	pub const fn finish(self) -> u64 {
+		self.0
+	}
+}
+
+macro_rules! impl_layout_fingerprint {
+	[ $($t:ty),* $(,)? ] => {
+		$(
+			impl LayoutFingerprint for $t {
+				const LAYOUT_FINGERPRINT: u64 = FingerprintHasher::new()
+					.write_str(stringify!($t))
+					.write_usize(size_of::<$t>())
+					.write_usize(align_of::<$t>())
+					.finish();
+			}
+		)*
+	};
+}
+
+impl_layout_fingerprint![
+	u8,
+	u16,
+	u32,
+	u64,
+	u128,
+	usize,
+	i8,
+	i16,
+	i32,
+	i64,
+	i128,
+	isize,
+	f32,
+	f64,
+	bool,
+	char,
+	(),
+];
+
+impl<T, const N: usize> LayoutFingerprint for [T; N]
+where
+	T: LayoutFingerprint,
+{
+	const LAYOUT_FINGERPRINT: u64 = FingerprintHasher::new()
+		.write_str("[T; N]")
+		.write_u64(T::LAYOUT_FINGERPRINT)
+		.write_usize(N)
+		.finish();
+}
+
+/// Checks the fingerprint stored with the data against the fingerprint of `T`.
+#[inline]
+pub const fn check_fingerprint<T>(fingerprint: u64) -> Result<(), TransmuteErrKind>
+where
+	T: LayoutFingerprint,
+{
+	if fingerprint != T::LAYOUT_FINGERPRINT {
+		return Err(TransmuteErrKind::layout_mismatch(
+			fingerprint,
+			T::LAYOUT_FINGERPRINT,
+		));
+	}
+
+	Ok(())
+}
+
+/// The header with the fingerprint of `T` (`u64`, little-endian), which is
+/// written before the data of `T` (see `Versioned`, `mmap::MappedView::open_versioned`).
+#[inline]
+pub const fn fingerprint_header<T>() -> [u8; 8]
+where
+	T: LayoutFingerprint,
+{
+	T::LAYOUT_FINGERPRINT.to_le_bytes()
+}
+
+/// Reads the fingerprint from the header.
+#[inline]
+pub const fn read_fingerprint_header(header: [u8; 8]) -> u64 {
+	u64::from_le_bytes(header)
+}
+
+/// A value with a header containing the layout fingerprint of its type.
+///
+/// The header is written before the value (`u64`, little-endian), when
+/// reading back, the stored fingerprint is compared with the fingerprint of
+/// the current build of `T` (`TransmuteErrKind::LayoutMismatch`).
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Versioned<T> {
+	fingerprint: [u8; 8],
+	value: T,
+}
+
+impl<T> Versioned<T>
+where
+	T: LayoutFingerprint,
+{
+	/// Checking the absence of padding between the header and the value at compile time
+	#[doc(hidden)]
+	pub const TYPE_NO_PADDING_ASSERT: () = [()][
+		// If you read this in the error logs, then the alignment of `T` is
+		// larger than the header (8 bytes) and padding appears after the header.
+		(size_of::<Self>() != size_of::<[u8; 8]>() + size_of::<T>()) as usize
+	];
+
+	/// Create a value with the fingerprint of the current build of `T`.
+	#[inline]
+	pub const fn new(value: T) -> Self {
+		Self {
+			fingerprint: fingerprint_header::<T>(),
+			value,
+		}
+	}
+
+	/// The stored fingerprint.
+	#[inline]
+	pub const fn fingerprint(&self) -> u64 {
+		read_fingerprint_header(self.fingerprint)
+	}
+
+	/// Whether the stored fingerprint matches the fingerprint of `T`.
+	#[inline]
+	pub const fn is_current(&self) -> bool {
+		self.fingerprint() == T::LAYOUT_FINGERPRINT
+	}
+
+	/// Get a reference to the value if the stored fingerprint matches.
+	#[inline]
+	pub fn as_value(&self) -> Result<&T, TransmuteErr<&Self>> {
+		match check_fingerprint::<T>(self.fingerprint()) {
+			Ok(()) => Ok(&self.value),
+			Err(e) => Err(TransmuteErr::new(e, self)),
+		}
+	}
+
+	/// Get the value if the stored fingerprint matches.
+	#[inline]
+	pub fn into_value(self) -> Result<T, TransmuteErr<Self>> {
+		match check_fingerprint::<T>(self.fingerprint()) {
+			Ok(()) => Ok(self.value),
+			Err(e) => Err(TransmuteErr::new(e, self)),
+		}
+	}
+
+	/// View the header and the value as bytes.
+	#[inline]
+	pub const fn as_bytes(&self) -> &[u8]
+	where
+		T: NoPadding,
+	{
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_header_padding = Self::TYPE_NO_PADDING_ASSERT;
+
+		unsafe { core::slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
+	}
+
+	/// Reinterprets the bytes (header and value) as a reference to `T`.
+	///
+	/// If the length or alignment of the bytes does not fit, or the stored
+	/// fingerprint does not match the fingerprint of `T`, an error occurs.
+	#[inline]
+	pub fn from_bytes(bytes: &[u8]) -> Result<&T, TransmuteErr<&[u8]>>
+	where
+		T: AnyBitPattern,
+	{
+		if let Err(e) = crate::bytes::check_layout::<Self>(bytes.len(), bytes.as_ptr()) {
+			return Err(TransmuteErr::new(e, bytes));
+		}
+
+		let versioned = unsafe { &*(bytes.as_ptr() as *const Self) };
+		if let Err(e) = check_fingerprint::<T>(versioned.fingerprint()) {
+			return Err(TransmuteErr::new(e, bytes));
+		}
+
+		Ok(&versioned.value)
+	}
+
+	/// Reads a copy of `T` from the bytes (header and value), the bytes do not
+	/// need to be aligned.
+	///
+	/// If the length of the bytes does not fit, or the stored fingerprint does
+	/// not match the fingerprint of `T`, an error occurs.
+	#[inline]
+	pub const fn read_from(bytes: &[u8]) -> Result<T, TransmuteErr<&[u8]>>
+	where
+		T: AnyBitPattern,
+	{
+		{
+			// Data dimension check
+			let size_d = bytes.len();
+			let size_to = size_of::<Self>();
+
+			if size_d != size_to {
+				let err = TransmuteErr::size_mismatch(size_d, size_to, bytes);
+
+				return Err(err);
+			}
+		}
+
+		let versioned: Self = unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const Self) };
+		if let Err(e) = check_fingerprint::<T>(versioned.fingerprint()) {
+			return Err(TransmuteErr::new(e, bytes));
+		}
+
+		Ok(versioned.value)
+	}
+}
+
+impl<T> Debug for Versioned<T>
+where
+	T: Debug,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("Versioned")
+			.field("fingerprint", &u64::from_le_bytes(self.fingerprint))
+			.field("value", &self.value)
+			.finish()
+	}
+}
@@ -51,25 +51,76 @@ fn main() {
 #![allow(clippy::tabs_in_doc_comments)]
 #![allow(clippy::needless_doctest_main)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
-#![cfg_attr(not(feature = "stderr"), no_std)]
+#![cfg_attr(feature = "nightly_const_trait", feature(const_trait_impl))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-/// Basic functions for dealing with memory.
-///
-/// (An optional module for ensuring compatibility with the standard library, which is turned on and off with the `compatible_stdapi` build flag.)
 #[cfg_attr(docsrs, doc(cfg(feature = "compatible_stdapi")))]
 #[cfg(any(test, feature = "compatible_stdapi"))]
-pub mod mem {
-	pub use crate::raw::transmute_unchecked;
-	/// Reinterprets the bits of a value of one type as another type.
-	/// The function is completely constant, in case of a size mismatch, a panic pops up.
-	pub use crate::try_transmute_or_panic as transmute;
-}
+pub mod mem;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "compatible_stdapi")))]
+#[cfg(any(test, feature = "compatible_stdapi"))]
+pub mod uninit;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "specialize")))]
+#[cfg(any(test, feature = "specialize"))]
+pub mod specialize;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
 #[cfg(any(test, feature = "try_transmute"))]
 pub mod err;
+#[cfg(any(test, feature = "try_transmute", feature = "compatible_stdapi"))]
+mod marker;
 mod raw;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod bytes;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod endian;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod view;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod layout;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod atomic;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod erased;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod aligned;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub mod ptr;
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "try_transmute"))))]
+#[cfg(all(feature = "std", any(test, feature = "try_transmute")))]
+pub mod io;
+
+#[cfg_attr(
+	docsrs,
+	doc(cfg(all(feature = "mmap", unix, feature = "try_transmute")))
+)]
+#[cfg(all(feature = "mmap", unix, any(test, feature = "try_transmute")))]
+pub mod mmap;
+
+pub mod wrapper;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "to")))]
 #[cfg(any(test, feature = "to"))]
 pub mod to;
@@ -144,3 +195,155 @@ pub const unsafe fn try_transmute<D, To>(in_data: D) -> Result<To, TransmuteErr<
 
 	Ok(unsafe { transmute_unchecked(in_data) })
 }
+
+/// A constant function reads the bits of the borrowed value as another type,
+/// the source is not consumed.
+///
+/// # Safety
+///
+/// If `To` is larger than `D`, or the alignment of `To` is larger than
+/// the alignment of `D`, a panic arises.
+#[track_caller]
+#[cfg_attr(
+	all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+	inline
+)]
+#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub const unsafe fn try_transmute_copy_or_panic<D, To>(in_data: &D) -> To {
+	use crate::err::TransmuteErrKind;
+	use core::mem::align_of;
+	use core::mem::size_of;
+	{
+		// Data dimension check
+		let size_d = size_of::<D>();
+		let size_to = size_of::<To>();
+
+		if size_to > size_d {
+			let errkind = TransmuteErrKind::size_exceeded(size_d, size_to);
+
+			errkind.unwrap();
+		}
+	}
+	{
+		// Data alignment check
+		let align_d = align_of::<D>();
+		let align_to = align_of::<To>();
+
+		if align_to > align_d {
+			let errkind = TransmuteErrKind::align_mismatch(align_d, align_to);
+
+			errkind.unwrap();
+		}
+	}
+
+	unsafe { core::ptr::read(in_data as *const D as *const To) }
+}
+
+/// A constant function reads the bits of the borrowed value as another type,
+/// the source is not consumed.
+///
+/// # Safety
+///
+/// If `To` is larger than `D`, or the alignment of `To` is larger than
+/// the alignment of `D`, an error occurs.
+#[cfg_attr(
+	all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+	inline
+)]
+#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub const unsafe fn try_transmute_copy<D, To>(in_data: &D) -> Result<To, TransmuteErr<&D>> {
+	use core::mem::align_of;
+	use core::mem::size_of;
+	{
+		// Data dimension check
+		let size_d = size_of::<D>();
+		let size_to = size_of::<To>();
+
+		if size_to > size_d {
+			let err = TransmuteErr::size_exceeded(size_d, size_to, in_data);
+
+			return Err(err);
+		}
+	}
+	{
+		// Data alignment check
+		let align_d = align_of::<D>();
+		let align_to = align_of::<To>();
+
+		if align_to > align_d {
+			let err = TransmuteErr::align_mismatch(align_d, align_to, in_data);
+
+			return Err(err);
+		}
+	}
+
+	Ok(unsafe { core::ptr::read(in_data as *const D as *const To) })
+}
+
+/// A constant function reads the bits of the borrowed value as another type
+/// without alignment requirements, the source is not consumed.
+///
+/// # Safety
+///
+/// If `To` is larger than `D`, an error occurs.
+#[cfg_attr(
+	all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+	inline
+)]
+#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub const unsafe fn try_transmute_copy_unaligned<D, To>(
+	in_data: &D,
+) -> Result<To, TransmuteErr<&D>> {
+	use core::mem::size_of;
+	{
+		// Data dimension check
+		let size_d = size_of::<D>();
+		let size_to = size_of::<To>();
+
+		if size_to > size_d {
+			let err = TransmuteErr::size_exceeded(size_d, size_to, in_data);
+
+			return Err(err);
+		}
+	}
+
+	Ok(unsafe { core::ptr::read_unaligned(in_data as *const D as *const To) })
+}
+
+/// A constant function reads the bits of the borrowed value as another type
+/// without alignment requirements, the source is not consumed.
+///
+/// # Safety
+///
+/// If `To` is larger than `D`, a panic arises.
+#[track_caller]
+#[cfg_attr(
+	all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+	inline
+)]
+#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub const unsafe fn try_transmute_copy_unaligned_or_panic<D, To>(in_data: &D) -> To {
+	use crate::err::TransmuteErrKind;
+	use core::mem::size_of;
+	{
+		// Data dimension check
+		let size_d = size_of::<D>();
+		let size_to = size_of::<To>();
+
+		if size_to > size_d {
+			let errkind = TransmuteErrKind::size_exceeded(size_d, size_to);
+
+			errkind.unwrap();
+		}
+	}
+
+	unsafe { core::ptr::read_unaligned(in_data as *const D as *const To) }
+}
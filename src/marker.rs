@@ -0,0 +1,122 @@
+//! Marker traits describing the valid bit patterns of a type
+//! (re-exported by `mem` and `bytes`).
+
+use core::cell::Cell;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::marker::PhantomPinned;
+use core::mem::ManuallyDrop;
+use core::mem::MaybeUninit;
+use core::num::NonZero;
+use core::num::Saturating;
+use core::num::Wrapping;
+use core::ptr::NonNull;
+
+/// Types for which a value with all bytes equal to zero is valid.
+///
+/// # Safety
+///
+/// An all-zero bit pattern must be a valid value of the type.
+pub unsafe trait Zeroable: Sized {}
+
+/// Types that have no padding (uninitialized) bytes, so any value can be
+/// viewed as `[u8; size_of::<Self>()]`.
+///
+/// # Safety
+///
+/// The type must not contain padding bytes or other uninitialized bytes
+/// (including the padding of the inner types and enum variants).
+pub unsafe trait NoPadding: Copy + 'static {}
+
+/// Types for which any bit pattern (of `size_of::<Self>()` bytes) is a valid value.
+///
+/// Since the all-zero bit pattern is one of them, every such type is also
+/// [Zeroable].
+///
+/// # Safety
+///
+/// Every bit pattern, including all-zero, must be a valid value of the type.
+pub unsafe trait AnyBitPattern: Zeroable + Copy + 'static {}
+
+macro_rules! unsafe_impl_marker {
+	[ $trait:ident: $( $(#[$meta:meta])* [$($gen:tt)*] $t:ty ),* $(,)? ] => {
+		$(
+			$(#[$meta])*
+			unsafe impl<$($gen)*> $trait for $t {}
+		)*
+	};
+}
+
+unsafe_impl_marker![
+	Zeroable:
+	[] (),
+	[] bool,
+	[] char,
+	[] u8, [] u16, [] u32, [] u64, [] u128, [] usize,
+	[] i8, [] i16, [] i32, [] i64, [] i128, [] isize,
+	[] f32, [] f64,
+	[T] *const T,
+	[T] *mut T,
+	[T: ?Sized] PhantomData<T>,
+	[] PhantomPinned,
+	[T] MaybeUninit<T>,
+	[T: Zeroable] ManuallyDrop<T>,
+	[T: Zeroable] Wrapping<T>,
+	[T: Zeroable] Saturating<T>,
+	[T: Zeroable] Cell<T>,
+	[T: Zeroable] UnsafeCell<T>,
+	[T: Zeroable, const N: usize] [T; N],
+	[T] Option<&T>,
+	[T] Option<&mut T>,
+	[T] Option<NonNull<T>>,
+	[] Option<NonZero<u8>>, [] Option<NonZero<u16>>, [] Option<NonZero<u32>>,
+	[] Option<NonZero<u64>>, [] Option<NonZero<u128>>, [] Option<NonZero<usize>>,
+	[] Option<NonZero<i8>>, [] Option<NonZero<i16>>, [] Option<NonZero<i32>>,
+	[] Option<NonZero<i64>>, [] Option<NonZero<i128>>, [] Option<NonZero<isize>>,
+	[A: Zeroable] (A,),
+	[A: Zeroable, B: Zeroable] (A, B),
+	[A: Zeroable, B: Zeroable, C: Zeroable] (A, B, C),
+	[A: Zeroable, B: Zeroable, C: Zeroable, D: Zeroable] (A, B, C, D),
+	#[cfg(feature = "alloc")]
+	[T] Option<alloc::boxed::Box<T>>,
+];
+
+unsafe_impl_marker![
+	NoPadding:
+	[] (),
+	[] bool,
+	[] char,
+	[] u8, [] u16, [] u32, [] u64, [] u128, [] usize,
+	[] i8, [] i16, [] i32, [] i64, [] i128, [] isize,
+	[] f32, [] f64,
+	[T: ?Sized + 'static] PhantomData<T>,
+	[T: NoPadding] ManuallyDrop<T>,
+	[T: NoPadding] Wrapping<T>,
+	[T: NoPadding] Saturating<T>,
+	[T: NoPadding, const N: usize] [T; N],
+	[] NonZero<u8>, [] NonZero<u16>, [] NonZero<u32>,
+	[] NonZero<u64>, [] NonZero<u128>, [] NonZero<usize>,
+	[] NonZero<i8>, [] NonZero<i16>, [] NonZero<i32>,
+	[] NonZero<i64>, [] NonZero<i128>, [] NonZero<isize>,
+	[] Option<NonZero<u8>>, [] Option<NonZero<u16>>, [] Option<NonZero<u32>>,
+	[] Option<NonZero<u64>>, [] Option<NonZero<u128>>, [] Option<NonZero<usize>>,
+	[] Option<NonZero<i8>>, [] Option<NonZero<i16>>, [] Option<NonZero<i32>>,
+	[] Option<NonZero<i64>>, [] Option<NonZero<i128>>, [] Option<NonZero<isize>>,
+];
+
+unsafe_impl_marker![
+	AnyBitPattern:
+	[] (),
+	[] u8, [] u16, [] u32, [] u64, [] u128, [] usize,
+	[] i8, [] i16, [] i32, [] i64, [] i128, [] isize,
+	[] f32, [] f64,
+	[T: ?Sized + 'static] PhantomData<T>,
+	[T: AnyBitPattern] ManuallyDrop<T>,
+	[T: AnyBitPattern] Wrapping<T>,
+	[T: AnyBitPattern] Saturating<T>,
+	[T: AnyBitPattern, const N: usize] [T; N],
+	[] Option<NonZero<u8>>, [] Option<NonZero<u16>>, [] Option<NonZero<u32>>,
+	[] Option<NonZero<u64>>, [] Option<NonZero<u128>>, [] Option<NonZero<usize>>,
+	[] Option<NonZero<i8>>, [] Option<NonZero<i16>>, [] Option<NonZero<i32>>,
+	[] Option<NonZero<i64>>, [] Option<NonZero<i128>>, [] Option<NonZero<isize>>,
+];
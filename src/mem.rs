@@ -0,0 +1,51 @@
+//! Basic functions for dealing with memory.
+//!
+//! (An optional module for ensuring compatibility with the standard library, which is turned on and off with the `compatible_stdapi` build flag.)
+//!
+//! The module re-exports everything from `core::mem`, replacing the functions
+//! that are constrained there, so `use cluFullTransmute::mem;` can shadow `core::mem`.
+
+pub use core::mem::*;
+
+pub use crate::raw::transmute_unchecked;
+/// Reads the bits of the borrowed value as another type, the source is not consumed.
+/// The function is completely constant, in case the output type is larger, a panic pops up.
+///
+/// There is deliberately no alignment check: as in `core::mem::transmute_copy`,
+/// the value is read unaligned, so `To` may be more aligned than the source.
+/// To reject such types instead, use `try_transmute_copy_or_panic` (or
+/// `try_transmute_copy`), which checks both the size and the alignment.
+pub use crate::try_transmute_copy_unaligned_or_panic as transmute_copy;
+/// Reinterprets the bits of a value of one type as another type.
+/// The function is completely constant, in case of a size mismatch, a panic pops up.
+pub use crate::try_transmute_or_panic as transmute;
+pub use crate::uninit::array_assume_init;
+pub use crate::uninit::slice_assume_init_mut;
+pub use crate::uninit::slice_assume_init_ref;
+pub use crate::uninit::uninit_array;
+pub use crate::uninit::write_slice;
+
+pub use crate::marker::Zeroable;
+
+/// Returns the value of type `T` represented by the all-zero byte-pattern.
+///
+/// Unlike `core::mem::zeroed`, the function is safe because it is only
+/// available for types for which the all-zero byte-pattern is valid.
+#[inline]
+pub const fn zeroed<T>() -> T
+where
+	T: Zeroable,
+{
+	unsafe { core::mem::zeroed() }
+}
+
+/// Replaces `dest` with the all-zero value of `T`, returning the previous `dest` value.
+///
+/// (A constant analogue of `take` for types without a const `Default`.)
+#[inline]
+pub const fn take_zeroed<T>(dest: &mut T) -> T
+where
+	T: Zeroable,
+{
+	replace(dest, zeroed())
+}
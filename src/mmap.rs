@@ -0,0 +1,450 @@
+//! Typed views over memory-mapped files made of fixed-size records.
+//!
+//! The length and alignment of the mapped data are checked in the same way
+//! as for `view::ByteView::as_slice`, errors are returned as
+//! `std::io::ErrorKind::InvalidData` with `TransmuteErrKind` as the reason.
+//!
+//! ```rust
+//! use cluFullTransmute::bytes::AnyBitPattern;
+//! use cluFullTransmute::bytes::NoPadding;
+//! use cluFullTransmute::bytes::Zeroable;
+//! use cluFullTransmute::layout::FingerprintHasher;
+//! use cluFullTransmute::layout::LayoutFingerprint;
+//! use cluFullTransmute::layout::fingerprint_header;
+//! use cluFullTransmute::mmap::MappedView;
+//! use std::io::Write;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Record {
+//! 	key: u32,
+//! 	offset: u32,
+//! }
+//!
+//! unsafe impl NoPadding for Record {}
+//! unsafe impl AnyBitPattern for Record {}
+//! unsafe impl Zeroable for Record {}
+//!
+//! impl LayoutFingerprint for Record {
+//! 	const LAYOUT_FINGERPRINT: u64 = FingerprintHasher::new()
+//! 		.write_field::<u32>("key", core::mem::offset_of!(Record, key), "u32")
+//! 		.write_field::<u32>("offset", core::mem::offset_of!(Record, offset), "u32")
+//! 		.finish();
+//! }
+//!
+//! # #[cfg(miri)] fn main() {} // file mappings are not supported by miri
+//! # #[cfg(not(miri))] fn main() {
+//! let path = std::env::temp_dir().join(format!("cluFullTransmute-mmap-doc-{}", std::process::id()));
+//! {
+//! 	let mut file = std::fs::File::create(&path).unwrap();
+//! 	file.write_all(&fingerprint_header::<Record>()).unwrap();
+//! 	for a in [Record { key: 1, offset: 0 }, Record { key: 2, offset: 64 }] {
+//! 		cluFullTransmute::io::write_transmuted(&mut file, &a).unwrap();
+//! 	}
+//! }
+//!
+//! // The file is not changed by anyone while it is mapped.
+//! let index = unsafe { MappedView::<Record>::open_versioned(&path) }.unwrap();
+//! assert_eq!(index[1], Record { key: 2, offset: 64 });
+//!
+//! drop(index);
+//! std::fs::remove_file(&path).unwrap();
+//! # }
+//! ```
+
+use crate::bytes::AnyBitPattern;
+use crate::bytes::NoPadding;
+use crate::err::TransmuteErrKind;
+use crate::layout::LayoutFingerprint;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::ops::Deref;
+use core::ops::DerefMut;
+use core::ptr::NonNull;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+
+/// The mapped memory of the whole file (`MAP_SHARED`).
+struct Mapping {
+	ptr: NonNull<u8>,
+	len: usize,
+}
+
+unsafe impl Send for Mapping {}
+unsafe impl Sync for Mapping {}
+
+impl Mapping {
+	fn new(file: &File, writable: bool) -> io::Result<Self> {
+		let len = match usize::try_from(file.metadata()?.len()) {
+			Ok(a) => a,
+			Err(_) => {
+				return Err(io::Error::new(
+					io::ErrorKind::InvalidData,
+					"the file is too large to be mapped",
+				));
+			}
+		};
+
+		if len == 0 {
+			// `mmap` does not accept an empty mapping.
+			return Ok(Self {
+				ptr: NonNull::dangling(),
+				len,
+			});
+		}
+
+		let prot = match writable {
+			true => libc::PROT_READ | libc::PROT_WRITE,
+			false => libc::PROT_READ,
+		};
+		let ptr = unsafe {
+			libc::mmap(
+				core::ptr::null_mut(),
+				len,
+				prot,
+				libc::MAP_SHARED,
+				file.as_raw_fd(),
+				0,
+			)
+		};
+		if ptr == libc::MAP_FAILED {
+			return Err(io::Error::last_os_error());
+		}
+
+		Ok(Self {
+			ptr: unsafe { NonNull::new_unchecked(ptr as *mut u8) },
+			len,
+		})
+	}
+
+	/// Checks the fingerprint header of `T` and returns the offset of the records.
+	fn versioned_offset<T>(&self) -> Result<usize, TransmuteErrKind>
+	where
+		T: LayoutFingerprint,
+	{
+		const HEADER_SIZE: usize = size_of::<[u8; 8]>();
+
+		// Data dimension check
+		if self.len < HEADER_SIZE {
+			return Err(TransmuteErrKind::size_exceeded(self.len, HEADER_SIZE));
+		}
+
+		let header = unsafe { core::ptr::read_unaligned(self.ptr.as_ptr() as *const [u8; 8]) };
+		crate::layout::check_fingerprint::<T>(crate::layout::read_fingerprint_header(header))?;
+
+		Ok(HEADER_SIZE)
+	}
+
+	/// Checks the records of `T` starting from `offset`.
+	fn records<T>(&self, offset: usize) -> Result<(NonNull<T>, usize), TransmuteErrKind> {
+		let len = self.len - offset;
+		let ptr = unsafe { self.ptr.add(offset) };
+
+		match crate::bytes::check_slice::<T>(len, ptr.as_ptr())? {
+			0 => Ok((NonNull::dangling(), 0)),
+			count => Ok((ptr.cast(), count)),
+		}
+	}
+
+	fn flush(&self) -> io::Result<()> {
+		if self.len == 0 {
+			return Ok(());
+		}
+
+		match unsafe { libc::msync(self.ptr.as_ptr() as *mut _, self.len, libc::MS_SYNC) } {
+			0 => Ok(()),
+			_ => Err(io::Error::last_os_error()),
+		}
+	}
+}
+
+impl Drop for Mapping {
+	fn drop(&mut self) {
+		if self.len != 0 {
+			unsafe {
+				libc::munmap(self.ptr.as_ptr() as *mut _, self.len);
+			}
+		}
+	}
+}
+
+/// A read-only memory-mapped file viewed as `[T]`.
+pub struct MappedView<T> {
+	_map: Mapping,
+	data: NonNull<T>,
+	len: usize,
+	_pp: PhantomData<T>,
+}
+
+unsafe impl<T> Send for MappedView<T> where T: Sync {}
+unsafe impl<T> Sync for MappedView<T> where T: Sync {}
+
+impl<T> MappedView<T>
+where
+	T: AnyBitPattern,
+{
+	/// Open and map the file, the whole file is viewed as records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be modified or truncated (by this or another process)
+	/// while it is mapped.
+	#[inline]
+	pub unsafe fn open<P>(path: P) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		unsafe { Self::map(&File::open(path)?) }
+	}
+
+	/// Map the file, the whole file is viewed as records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be modified or truncated (by this or another process)
+	/// while it is mapped.
+	#[inline]
+	pub unsafe fn map(file: &File) -> io::Result<Self> {
+		Self::with_offset(Mapping::new(file, false)?, |_| Ok(0))
+	}
+
+	/// Open and map the file, the file must start with the fingerprint header
+	/// of `T` (`layout::fingerprint_header`) followed by the records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be modified or truncated (by this or another process)
+	/// while it is mapped.
+	#[inline]
+	pub unsafe fn open_versioned<P>(path: P) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+		T: LayoutFingerprint,
+	{
+		unsafe { Self::map_versioned(&File::open(path)?) }
+	}
+
+	/// Map the file, the file must start with the fingerprint header of `T`
+	/// (`layout::fingerprint_header`) followed by the records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be modified or truncated (by this or another process)
+	/// while it is mapped.
+	#[inline]
+	pub unsafe fn map_versioned(file: &File) -> io::Result<Self>
+	where
+		T: LayoutFingerprint,
+	{
+		Self::with_offset(Mapping::new(file, false)?, Mapping::versioned_offset::<T>)
+	}
+
+	fn with_offset(
+		map: Mapping,
+		offset: impl FnOnce(&Mapping) -> Result<usize, TransmuteErrKind>,
+	) -> io::Result<Self> {
+		let (data, len) = map.records::<T>(offset(&map)?)?;
+
+		Ok(Self {
+			_map: map,
+			data,
+			len,
+			_pp: PhantomData,
+		})
+	}
+
+	/// Number of records.
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Whether there are no records.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// View the records as a slice.
+	#[inline]
+	pub const fn as_slice(&self) -> &[T] {
+		unsafe { core::slice::from_raw_parts(self.data.as_ptr(), self.len) }
+	}
+}
+
+impl<T> Deref for MappedView<T>
+where
+	T: AnyBitPattern,
+{
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<T> Debug for MappedView<T>
+where
+	T: AnyBitPattern + Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		f.debug_list().entries(self.as_slice()).finish()
+	}
+}
+
+/// A read-write memory-mapped file viewed as `[T]`, changes are written to the file.
+pub struct MappedViewMut<T> {
+	map: Mapping,
+	data: NonNull<T>,
+	len: usize,
+	_pp: PhantomData<T>,
+}
+
+unsafe impl<T> Send for MappedViewMut<T> where T: Send {}
+unsafe impl<T> Sync for MappedViewMut<T> where T: Sync {}
+
+impl<T> MappedViewMut<T>
+where
+	T: AnyBitPattern + NoPadding,
+{
+	/// Open the file for reading and writing and map it, the whole file is
+	/// viewed as records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be accessed or truncated (by this or another process)
+	/// except through this view while it is mapped.
+	#[inline]
+	pub unsafe fn open<P>(path: P) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		unsafe { Self::map(&OpenOptions::new().read(true).write(true).open(path)?) }
+	}
+
+	/// Map the file (opened for reading and writing), the whole file is viewed
+	/// as records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be accessed or truncated (by this or another process)
+	/// except through this view while it is mapped.
+	#[inline]
+	pub unsafe fn map(file: &File) -> io::Result<Self> {
+		Self::with_offset(Mapping::new(file, true)?, |_| Ok(0))
+	}
+
+	/// Open the file for reading and writing and map it, the file must start
+	/// with the fingerprint header of `T` (`layout::fingerprint_header`)
+	/// followed by the records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be accessed or truncated (by this or another process)
+	/// except through this view while it is mapped.
+	#[inline]
+	pub unsafe fn open_versioned<P>(path: P) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+		T: LayoutFingerprint,
+	{
+		unsafe { Self::map_versioned(&OpenOptions::new().read(true).write(true).open(path)?) }
+	}
+
+	/// Map the file (opened for reading and writing), the file must start with
+	/// the fingerprint header of `T` (`layout::fingerprint_header`) followed by
+	/// the records of `T`.
+	///
+	/// # Safety
+	///
+	/// The file must not be accessed or truncated (by this or another process)
+	/// except through this view while it is mapped.
+	#[inline]
+	pub unsafe fn map_versioned(file: &File) -> io::Result<Self>
+	where
+		T: LayoutFingerprint,
+	{
+		Self::with_offset(Mapping::new(file, true)?, Mapping::versioned_offset::<T>)
+	}
+
+	fn with_offset(
+		map: Mapping,
+		offset: impl FnOnce(&Mapping) -> Result<usize, TransmuteErrKind>,
+	) -> io::Result<Self> {
+		let (data, len) = map.records::<T>(offset(&map)?)?;
+
+		Ok(Self {
+			map,
+			data,
+			len,
+			_pp: PhantomData,
+		})
+	}
+
+	/// Number of records.
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Whether there are no records.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// View the records as a slice.
+	#[inline]
+	pub const fn as_slice(&self) -> &[T] {
+		unsafe { core::slice::from_raw_parts(self.data.as_ptr(), self.len) }
+	}
+
+	/// View the records as a mutable slice.
+	#[inline]
+	pub const fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe { core::slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+	}
+
+	/// Write the changes to the file (`msync`).
+	#[inline]
+	pub fn flush(&self) -> io::Result<()> {
+		self.map.flush()
+	}
+}
+
+impl<T> Deref for MappedViewMut<T>
+where
+	T: AnyBitPattern + NoPadding,
+{
+	type Target = [T];
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<T> DerefMut for MappedViewMut<T>
+where
+	T: AnyBitPattern + NoPadding,
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_slice()
+	}
+}
+
+impl<T> Debug for MappedViewMut<T>
+where
+	T: AnyBitPattern + NoPadding + Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		f.debug_list().entries(self.as_slice()).finish()
+	}
+}
@@ -0,0 +1,246 @@
+//! Reinterpretation of unsized data (`str`, slices and slice-tailed structs)
+//! with the handling of the pointer metadata (length).
+//!
+//! ```rust
+//! use cluFullTransmute::ptr;
+//!
+//! let data: &[u8] = ptr::str_as_bytes("Test");
+//! assert_eq!(ptr::bytes_as_str(data), Ok("Test"));
+//! assert!(ptr::bytes_as_str(&[0xff]).unwrap_err().is_invalid_utf8());
+//!
+//! let values: &[u32] = &[1, 2];
+//! let halves: &[u16] = ptr::slice_cast(values).unwrap();
+//! assert_eq!(halves.len(), 4);
+//! ```
+
+use crate::bytes::AnyBitPattern;
+use crate::bytes::NoPadding;
+use crate::bytes::check_align;
+use crate::bytes::check_slice;
+use crate::err::TransmuteErr;
+use crate::err::TransmuteErrKind;
+use core::mem::align_of;
+use core::mem::size_of;
+use core::mem::size_of_val;
+
+/// View the string as its bytes.
+#[inline]
+pub const fn str_as_bytes(s: &str) -> &[u8] {
+	s.as_bytes()
+}
+
+/// View the bytes as a string, if the bytes are not valid UTF-8, an error occurs.
+#[inline]
+pub const fn bytes_as_str(bytes: &[u8]) -> Result<&str, TransmuteErr<&[u8]>> {
+	match core::str::from_utf8(bytes) {
+		Ok(a) => Ok(a),
+		Err(e) => Err(TransmuteErr::invalid_utf8(e.valid_up_to(), bytes)),
+	}
+}
+
+/// View the mutable bytes as a mutable string, if the bytes are not valid
+/// UTF-8, an error occurs.
+#[inline]
+pub const fn bytes_as_str_mut(bytes: &mut [u8]) -> Result<&mut str, TransmuteErr<&mut [u8]>> {
+	if let Err(e) = core::str::from_utf8(bytes) {
+		return Err(TransmuteErr::invalid_utf8(e.valid_up_to(), bytes));
+	}
+
+	Ok(unsafe { core::str::from_utf8_unchecked_mut(bytes) })
+}
+
+/// View the bytes as a string without checking.
+///
+/// # Safety
+///
+/// The bytes must be valid UTF-8.
+#[inline]
+pub const unsafe fn bytes_as_str_unchecked(bytes: &[u8]) -> &str {
+	unsafe { core::str::from_utf8_unchecked(bytes) }
+}
+
+/// Reinterprets `[A]` as `[B]`, the length is scaled to the size of `B`.
+///
+/// If the size of the data is not a multiple of the size of `B`, or the data
+/// is not aligned for `B`, an error occurs.
+///
+/// This is the safe, checked slice cast: `bytes::slice_as_bytes` is its
+/// infallible special case for `B = u8`, and `to::ToTransmuteRef::transmute_slice`
+/// is the `unsafe` variant for types without `NoPadding`/`AnyBitPattern`
+/// (it panics on the size and leaves the alignment to the caller). All of them
+/// share the same size check.
+#[inline]
+pub fn slice_cast<A, B>(values: &[A]) -> Result<&[B], TransmuteErr<&[A]>>
+where
+	A: NoPadding,
+	B: AnyBitPattern,
+{
+	match check_slice::<B>(size_of_val(values), values.as_ptr() as *const u8) {
+		Ok(0) => Ok(&[]),
+		Ok(len) => Ok(unsafe { core::slice::from_raw_parts(values.as_ptr() as *const B, len) }),
+		Err(e) => Err(TransmuteErr::new(e, values)),
+	}
+}
+
+/// Reinterprets `[A]` as mutable `[B]`, the length is scaled to the size of `B`.
+///
+/// If the size of the data is not a multiple of the size of `B`, or the data
+/// is not aligned for `B`, an error occurs.
+#[inline]
+pub fn slice_cast_mut<A, B>(values: &mut [A]) -> Result<&mut [B], TransmuteErr<&mut [A]>>
+where
+	A: NoPadding + AnyBitPattern,
+	B: NoPadding + AnyBitPattern,
+{
+	match check_slice::<B>(size_of_val(values), values.as_ptr() as *const u8) {
+		Ok(0) => Ok(&mut []),
+		Ok(len) => {
+			Ok(unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut B, len) })
+		}
+		Err(e) => Err(TransmuteErr::new(e, values)),
+	}
+}
+
+/// A `#[repr(C)]` struct of a header followed by a slice
+/// (`struct Packet { header: Header, body: [u8] }`).
+///
+/// Use `declare_slice_dst!` to declare such a struct.
+///
+/// # Safety
+///
+/// The type must be `#[repr(C)]` with exactly two fields: `Header` and
+/// `[Item]`, and the pointer functions must only attach the length.
+pub unsafe trait SliceDst {
+	/// The sized header.
+	type Header: AnyBitPattern;
+	/// The item of the tail slice.
+	type Item: AnyBitPattern;
+
+	/// Create a pointer to the struct with `len` items in the tail.
+	fn ptr_from_raw_parts(data: *const u8, len: usize) -> *const Self;
+
+	/// Create a mutable pointer to the struct with `len` items in the tail.
+	fn ptr_from_raw_parts_mut(data: *mut u8, len: usize) -> *mut Self;
+}
+
+/// Checks the bytes for the slice-tailed struct, returns the number of items
+/// in the tail.
+fn check_slice_dst<T>(len: usize, ptr: *const u8) -> Result<usize, TransmuteErrKind>
+where
+	T: SliceDst + ?Sized,
+{
+	let size_header = size_of::<T::Header>();
+	let size_item = size_of::<T::Item>();
+	let align = match align_of::<T::Header>() > align_of::<T::Item>() {
+		true => align_of::<T::Header>(),
+		false => align_of::<T::Item>(),
+	};
+	// (`#[repr(C)]`: the tail starts at the first offset aligned for `Item`)
+	let offset = size_header.next_multiple_of(align_of::<T::Item>());
+
+	// Data dimension check
+	if len < offset {
+		return Err(TransmuteErrKind::size_exceeded(len, offset));
+	}
+	let size_tail = len - offset;
+	if size_item == 0 || !size_tail.is_multiple_of(size_item) {
+		return Err(TransmuteErrKind::size_mismatch(size_tail, size_item));
+	}
+	// (the struct must not have trailing padding beyond the data)
+	let size_to = len.next_multiple_of(align);
+	if len != size_to {
+		return Err(TransmuteErrKind::size_mismatch(len, size_to));
+	}
+
+	// Data alignment check
+	check_align::<T::Header>(ptr)?;
+	check_align::<T::Item>(ptr)?;
+
+	Ok(size_tail / size_item)
+}
+
+/// Reinterprets the bytes as a slice-tailed struct, the bytes after the
+/// header become the tail.
+///
+/// If the bytes are smaller than the header, the tail is not a whole number of
+/// items, or the bytes are not aligned for the struct, an error occurs.
+#[inline]
+pub fn dst_from_bytes<T>(bytes: &[u8]) -> Result<&T, TransmuteErr<&[u8]>>
+where
+	T: SliceDst + ?Sized,
+{
+	match check_slice_dst::<T>(bytes.len(), bytes.as_ptr()) {
+		Ok(len) => Ok(unsafe { &*T::ptr_from_raw_parts(bytes.as_ptr(), len) }),
+		Err(e) => Err(TransmuteErr::new(e, bytes)),
+	}
+}
+
+/// Reinterprets the mutable bytes as a mutable slice-tailed struct, the bytes
+/// after the header become the tail.
+///
+/// If the bytes are smaller than the header, the tail is not a whole number of
+/// items, or the bytes are not aligned for the struct, an error occurs.
+#[inline]
+pub fn dst_from_bytes_mut<T>(bytes: &mut [u8]) -> Result<&mut T, TransmuteErr<&mut [u8]>>
+where
+	T: SliceDst + ?Sized,
+	T::Header: NoPadding,
+	T::Item: NoPadding,
+{
+	match check_slice_dst::<T>(bytes.len(), bytes.as_ptr()) {
+		Ok(len) => Ok(unsafe { &mut *T::ptr_from_raw_parts_mut(bytes.as_mut_ptr(), len) }),
+		Err(e) => Err(TransmuteErr::new(e, bytes)),
+	}
+}
+
+/// Declares a `#[repr(C)]` slice-tailed struct and implements `ptr::SliceDst` for it.
+///
+/// ```rust
+/// use cluFullTransmute::declare_slice_dst;
+/// use cluFullTransmute::ptr;
+///
+/// declare_slice_dst! {
+/// 	/// Header (`u16` length) and body.
+/// 	#[derive(Debug)]
+/// 	pub struct Packet {
+/// 		pub header: [u8; 2],
+/// 		pub body: [u8],
+/// 	}
+/// }
+///
+/// let packet: &Packet = ptr::dst_from_bytes(&[2, 0, b'H', b'i']).unwrap();
+/// assert_eq!(packet.header, [2, 0]);
+/// assert_eq!(&packet.body, b"Hi");
+/// ```
+#[macro_export]
+macro_rules! declare_slice_dst {
+	[
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident {
+			$(#[$hmeta:meta])* $hvis:vis $header:ident : $header_ty:ty,
+			$(#[$tmeta:meta])* $tvis:vis $tail:ident : [$item_ty:ty] $(,)?
+		}
+	] => {
+		$(#[$meta])*
+		#[repr(C)]
+		$vis struct $name {
+			$(#[$hmeta])* $hvis $header: $header_ty,
+			$(#[$tmeta])* $tvis $tail: [$item_ty],
+		}
+
+		unsafe impl $crate::ptr::SliceDst for $name {
+			type Header = $header_ty;
+			type Item = $item_ty;
+
+			#[inline]
+			fn ptr_from_raw_parts(data: *const u8, len: usize) -> *const Self {
+				::core::ptr::slice_from_raw_parts(data as *const $item_ty, len) as *const Self
+			}
+
+			#[inline]
+			fn ptr_from_raw_parts_mut(data: *mut u8, len: usize) -> *mut Self {
+				::core::ptr::slice_from_raw_parts_mut(data as *mut $item_ty, len) as *mut Self
+			}
+		}
+	};
+}
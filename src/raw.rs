@@ -2,9 +2,10 @@
 
 use core::mem::ManuallyDrop;
 
-union TransmutData<In, Out> {
-	r#in: ManuallyDrop<In>,
-	out: ManuallyDrop<Out>,
+/// Both views of the same bits, the size is the largest of `In` and `Out`.
+pub(crate) union TransmutData<In, Out> {
+	pub(crate) r#in: ManuallyDrop<In>,
+	pub(crate) out: ManuallyDrop<Out>,
 }
 
 /// Reinterprets the bits of a value of one type as another type.
@@ -0,0 +1,134 @@
+//! Safe specialization of generic code by comparing `TypeId`.
+//!
+//! ```rust
+//! use cluFullTransmute::specialize;
+//!
+//! fn len_in_bytes<T: 'static>(value: T) -> usize {
+//! 	specialize!(value, {
+//! 		String => |a| a.len(),
+//! 		&'static str => |a| a.len(),
+//! 		else => |_a| core::mem::size_of::<T>(),
+//! 	})
+//! }
+//!
+//! assert_eq!(len_in_bytes(String::from("Test")), 4);
+//! assert_eq!(len_in_bytes("Test1"), 5);
+//! assert_eq!(len_in_bytes(0u16), 2);
+//! ```
+
+use crate::raw::transmute_unchecked;
+use core::any::TypeId;
+
+/// Returns the value as `U` if `T` and `U` are the same type, otherwise
+/// returns the value back.
+#[inline]
+pub fn cast_if_same<T, U>(value: T) -> Result<U, T>
+where
+	T: 'static,
+	U: 'static,
+{
+	match TypeId::of::<T>() == TypeId::of::<U>() {
+		// `T` and `U` are the same type.
+		true => Ok(unsafe { transmute_unchecked(value) }),
+		false => Err(value),
+	}
+}
+
+/// Returns the reference as `&U` if `T` and `U` are the same type, otherwise
+/// returns the reference back.
+#[inline]
+pub fn cast_ref_if_same<T, U>(value: &T) -> Result<&U, &T>
+where
+	T: ?Sized + 'static,
+	U: ?Sized + 'static,
+{
+	match TypeId::of::<T>() == TypeId::of::<U>() {
+		// `T` and `U` are the same type.
+		true => Ok(unsafe { transmute_unchecked(value) }),
+		false => Err(value),
+	}
+}
+
+/// Returns the mutable reference as `&mut U` if `T` and `U` are the same
+/// type, otherwise returns the reference back.
+#[inline]
+pub fn cast_mut_if_same<T, U>(value: &mut T) -> Result<&mut U, &mut T>
+where
+	T: ?Sized + 'static,
+	U: ?Sized + 'static,
+{
+	match TypeId::of::<T>() == TypeId::of::<U>() {
+		// `T` and `U` are the same type.
+		true => Ok(unsafe { transmute_unchecked(value) }),
+		false => Err(value),
+	}
+}
+
+/// Selects the branch by the type of the value (`cast_if_same`), the
+/// candidates are checked in order, `else` is taken if none of them matched.
+///
+/// ```rust
+/// use cluFullTransmute::specialize;
+///
+/// fn to_u64<T: 'static + Copy>(value: T) -> Option<u64> {
+/// 	specialize!(value, {
+/// 		u8 => |a| Some(a as u64),
+/// 		u16 => |a| Some(a as u64),
+/// 		u32 => |a| Some(a as u64),
+/// 		u64 => |a| Some(a),
+/// 		else => |_a| None,
+/// 	})
+/// }
+///
+/// assert_eq!(to_u64(10u16), Some(10));
+/// assert_eq!(to_u64(-1i8), None);
+/// ```
+///
+/// With `ref` (`ref mut`), the referenced type is selected
+/// (`cast_ref_if_same`, `cast_mut_if_same`):
+///
+/// ```rust
+/// use cluFullTransmute::specialize;
+///
+/// fn sum<T: 'static>(values: &[T]) -> u64 {
+/// 	specialize!(ref values, {
+/// 		[u8] => |a| a.iter().map(|a| *a as u64).sum(),
+/// 		[u32] => |a| a.iter().map(|a| *a as u64).sum(),
+/// 		else => |_a| 0,
+/// 	})
+/// }
+///
+/// assert_eq!(sum(&[1u8, 2, 3]), 6);
+/// assert_eq!(sum(&[1i8]), 0);
+/// ```
+#[macro_export]
+macro_rules! specialize {
+	[ ref mut $value:expr, { $($tt:tt)* } ] => {
+		$crate::specialize!(@cast cast_mut_if_same, $value, { $($tt)* })
+	};
+	[ ref $value:expr, { $($tt:tt)* } ] => {
+		$crate::specialize!(@cast cast_ref_if_same, $value, { $($tt)* })
+	};
+	[
+		@cast $cast:ident, $value:expr, {
+			$( $ty:ty => |$a:pat_param| $e:expr ),+ ,
+			else => |$default:pat_param| $default_e:expr $(,)?
+		}
+	] => {
+		'specialize: {
+			let value = $value;
+			$(
+				let value = match $crate::specialize::$cast::<_, $ty>(value) {
+					Ok($a) => break 'specialize $e,
+					Err(a) => a,
+				};
+			)+
+
+			let $default = value;
+			$default_e
+		}
+	};
+	[ $value:expr, { $($tt:tt)* } ] => {
+		$crate::specialize!(@cast cast_if_same, $value, { $($tt)* })
+	};
+}
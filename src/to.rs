@@ -1,85 +1,324 @@
 //! A handy trait for converting any `T` to the desired `To` without directly calling
 //! crate functions.
 
+#[cfg(any(test, feature = "try_transmute"))]
 use crate::TransmuteErr;
+#[cfg(any(test, feature = "contract"))]
+use crate::contract::TransmuteContract;
+#[cfg(any(test, feature = "try_transmute"))]
+use crate::err::TransmuteErrKind;
+#[cfg(any(test, feature = "try_transmute"))]
+use core::mem::size_of;
+#[cfg(any(test, feature = "try_transmute"))]
+use core::mem::size_of_val;
 
-pub trait ToTransmute
-where
-	Self: Sized,
-{
-	/// A constant function reinterprets the bits of a value of one type as another type.
+/// Declares `ToTransmute`, the `const` keyword is passed only when
+/// the `nightly_const_trait` build flag is enabled.
+macro_rules! decl_to_transmute {
+	[ $($const:tt)? ] => {
+		/// Method-style access to the crate transmutation functions.
+		///
+		/// (With the `nightly_const_trait` build flag the trait is declared as a
+		/// `const trait` and its methods can be called in const contexts of crates
+		/// that enable `#![feature(const_trait_impl)]`.)
+		pub $($const)? trait ToTransmute
+		where
+			Self: Sized,
+		{
+			/// A constant function reinterprets the bits of a value of one type as another type.
+			///
+			/// # Safety
+			///
+			/// If the sizes do not match, a panic arises.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			unsafe fn try_transmute_or_panic<To>(self) -> To;
+
+			/// A constant function reinterprets the bits of a value of one type as another type.
+			///
+			/// # Safety
+			///
+			/// If the size does not match, an error occurs.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			unsafe fn try_transmute<To>(self) -> Result<To, TransmuteErr<Self>>;
+
+			/// Reinterprets the bits of a value of one type as another type.
+			/// The function is completely const, data dimensions are not checked.
+			///
+			/// # Safety
+			/// No protections.
+			unsafe fn transmute_unchecked<To>(self) -> To;
+
+			/// Reinterprets a reference to a value as a reference to another type.
+			///
+			/// # Safety
+			///
+			/// If the sizes do not match, a panic arises.
+			/// The alignment of `To` must be satisfied by the reference.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			unsafe fn transmute_ref<To>(&self) -> &To;
+
+			/// Reinterprets a mutable reference to a value as a mutable reference to another type.
+			///
+			/// # Safety
+			///
+			/// If the sizes do not match, a panic arises.
+			/// The alignment of `To` must be satisfied by the reference.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			unsafe fn transmute_mut<To>(&mut self) -> &mut To;
+
+			/// Create a contract for converting the value to `OUT`.
+			///
+			/// # Safety
+			///
+			/// Only the creator of the contract can guarantee that the converted type will match.
+			#[cfg_attr(docsrs, doc(cfg(feature = "contract")))]
+			#[cfg(any(test, feature = "contract"))]
+			unsafe fn into_contract<OUT>(self) -> TransmuteContract<Self, OUT>;
+		}
+
+		impl<T> $($const)? ToTransmute for T
+		where
+			T: Sized,
+		{
+			/// A constant function reinterprets the bits of a value of one type as another type.
+			///
+			/// # Safety
+			///
+			/// If the sizes do not match, a panic arises.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			#[cfg_attr(
+				all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+				inline
+			)]
+			#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+			unsafe fn try_transmute_or_panic<To>(self) -> To {
+				unsafe { crate::try_transmute_or_panic(self) }
+			}
+
+			/// A constant function reinterprets the bits of a value of one type as another type.
+			///
+			/// # Safety
+			///
+			/// If the size does not match, an error occurs.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			#[cfg_attr(
+				all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+				inline
+			)]
+			#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+			unsafe fn try_transmute<To>(self) -> Result<To, TransmuteErr<Self>> {
+				unsafe { crate::try_transmute(self) }
+			}
+
+			/// Reinterprets the bits of a value of one type as another type.
+			/// The function is completely const, data dimensions are not checked.
+			///
+			/// # Safety
+			/// No protections.
+			#[cfg_attr(
+				all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+				inline
+			)]
+			#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+			unsafe fn transmute_unchecked<To>(self) -> To {
+				unsafe { crate::raw::transmute_unchecked(self) }
+			}
+
+			/// Reinterprets a reference to a value as a reference to another type.
+			///
+			/// # Safety
+			///
+			/// If the sizes do not match, a panic arises.
+			/// The alignment of `To` must be satisfied by the reference.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			#[track_caller]
+			#[cfg_attr(
+				all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+				inline
+			)]
+			#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+			unsafe fn transmute_ref<To>(&self) -> &To {
+				check_size::<To>(size_of::<T>());
+
+				unsafe { &*(self as *const T as *const To) }
+			}
+
+			/// Reinterprets a mutable reference to a value as a mutable reference to another type.
+			///
+			/// # Safety
+			///
+			/// If the sizes do not match, a panic arises.
+			/// The alignment of `To` must be satisfied by the reference.
+			#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+			#[cfg(any(test, feature = "try_transmute"))]
+			#[track_caller]
+			#[cfg_attr(
+				all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+				inline
+			)]
+			#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+			unsafe fn transmute_mut<To>(&mut self) -> &mut To {
+				check_size::<To>(size_of::<T>());
+
+				unsafe { &mut *(self as *mut T as *mut To) }
+			}
+
+			/// Create a contract for converting the value to `OUT`.
+			///
+			/// # Safety
+			///
+			/// Only the creator of the contract can guarantee that the converted type will match.
+			#[cfg_attr(docsrs, doc(cfg(feature = "contract")))]
+			#[cfg(any(test, feature = "contract"))]
+			#[cfg_attr(
+				all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
+				inline
+			)]
+			#[cfg_attr(feature = "transmute-inline-always", inline(always))]
+			unsafe fn into_contract<OUT>(self) -> TransmuteContract<Self, OUT> {
+				unsafe { TransmuteContract::new_unchecked(self) }
+			}
+		}
+	};
+}
+
+#[cfg(feature = "nightly_const_trait")]
+decl_to_transmute![const];
+
+#[cfg(not(feature = "nightly_const_trait"))]
+decl_to_transmute![];
+
+/// A handy trait for reinterpreting unsized values (`[T]`, `str`)
+/// by reference without directly calling crate functions.
+#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
+#[cfg(any(test, feature = "try_transmute"))]
+pub trait ToTransmuteRef {
+	/// Reinterprets the referenced data as a reference to a sized type.
 	///
 	/// # Safety
 	///
-	/// If the sizes do not match, a panic arises.
-	#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
-	#[cfg(any(test, feature = "try_transmute"))]
-	unsafe fn try_transmute_or_panic<To>(self) -> To;
+	/// If the size of the data does not match the size of `To`, a panic arises.
+	/// The alignment of `To` must be satisfied by the reference.
+	unsafe fn transmute_ref<To>(&self) -> &To;
 
-	/// A constant function reinterprets the bits of a value of one type as another type.
+	/// Reinterprets the mutable referenced data as a mutable reference to a sized type.
 	///
 	/// # Safety
 	///
-	/// If the size does not match, an error occurs.
-	#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
-	#[cfg(any(test, feature = "try_transmute"))]
-	unsafe fn try_transmute<To>(self) -> Result<To, TransmuteErr<Self>>;
+	/// If the size of the data does not match the size of `To`, a panic arises.
+	/// The alignment of `To` must be satisfied by the reference.
+	unsafe fn transmute_mut<To>(&mut self) -> &mut To;
 
-	/// Reinterprets the bits of a value of one type as another type.
-	/// The function is completely const, data dimensions are not checked.
+	/// Reinterprets the referenced data as a slice of `To`,
+	/// the length is scaled to the size of `To`.
 	///
 	/// # Safety
-	/// No protections.
-	unsafe fn transmute_unchecked<To>(self) -> To;
-}
+	///
+	/// If the size of the data is not a multiple of the size of `To`, a panic arises.
+	/// The alignment of `To` must be satisfied by the reference.
+	unsafe fn transmute_slice<To>(&self) -> &[To];
 
-impl<T> ToTransmute for T
-where
-	T: Sized,
-{
-	/// A constant function reinterprets the bits of a value of one type as another type.
+	/// Reinterprets the mutable referenced data as a mutable slice of `To`,
+	/// the length is scaled to the size of `To`.
 	///
 	/// # Safety
 	///
-	/// If the sizes do not match, a panic arises.
-	#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
-	#[cfg(any(test, feature = "try_transmute"))]
-	#[cfg_attr(
-		all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
-		inline
-	)]
-	#[cfg_attr(feature = "transmute-inline-always", inline(always))]
-	unsafe fn try_transmute_or_panic<To>(self) -> To {
-		unsafe { crate::try_transmute_or_panic(self) }
+	/// If the size of the data is not a multiple of the size of `To`, a panic arises.
+	/// The alignment of `To` must be satisfied by the reference.
+	unsafe fn transmute_slice_mut<To>(&mut self) -> &mut [To];
+}
+
+#[cfg(any(test, feature = "try_transmute"))]
+impl<T> ToTransmuteRef for [T] {
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_ref<To>(&self) -> &To {
+		check_size::<To>(size_of_val(self));
+
+		unsafe { &*(self.as_ptr() as *const To) }
 	}
 
-	/// A constant function reinterprets the bits of a value of one type as another type.
-	///
-	/// # Safety
-	///
-	/// If the size does not match, an error occurs.
-	#[cfg_attr(docsrs, doc(cfg(feature = "try_transmute")))]
-	#[cfg(any(test, feature = "try_transmute"))]
-	#[cfg_attr(
-		all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
-		inline
-	)]
-	#[cfg_attr(feature = "transmute-inline-always", inline(always))]
-	unsafe fn try_transmute<To>(self) -> Result<To, TransmuteErr<Self>> {
-		unsafe { crate::try_transmute(self) }
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_mut<To>(&mut self) -> &mut To {
+		check_size::<To>(size_of_val(self));
+
+		unsafe { &mut *(self.as_mut_ptr() as *mut To) }
 	}
 
-	/// Reinterprets the bits of a value of one type as another type.
-	/// The function is completely const, data dimensions are not checked.
-	///
-	/// # Safety
-	/// No protections.
-	#[cfg_attr(
-		all(feature = "transmute-inline", not(feature = "transmute-inline-always")),
-		inline
-	)]
-	#[cfg_attr(feature = "transmute-inline-always", inline(always))]
-	unsafe fn transmute_unchecked<To>(self) -> To {
-		unsafe { crate::raw::transmute_unchecked(self) }
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_slice<To>(&self) -> &[To] {
+		let len = scaled_len::<To>(size_of_val(self));
+
+		unsafe { core::slice::from_raw_parts(self.as_ptr() as *const To, len) }
+	}
+
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_slice_mut<To>(&mut self) -> &mut [To] {
+		let len = scaled_len::<To>(size_of_val(self));
+
+		unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr() as *mut To, len) }
+	}
+}
+
+#[cfg(any(test, feature = "try_transmute"))]
+impl ToTransmuteRef for str {
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_ref<To>(&self) -> &To {
+		unsafe { ToTransmuteRef::transmute_ref(self.as_bytes()) }
+	}
+
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_mut<To>(&mut self) -> &mut To {
+		unsafe { ToTransmuteRef::transmute_mut(self.as_bytes_mut()) }
+	}
+
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_slice<To>(&self) -> &[To] {
+		unsafe { ToTransmuteRef::transmute_slice(self.as_bytes()) }
+	}
+
+	#[track_caller]
+	#[inline]
+	unsafe fn transmute_slice_mut<To>(&mut self) -> &mut [To] {
+		unsafe { ToTransmuteRef::transmute_slice_mut(self.as_bytes_mut()) }
+	}
+}
+
+/// Data dimension check, `size` must be equal to the size of `To`.
+#[cfg(any(test, feature = "try_transmute"))]
+#[track_caller]
+#[inline]
+const fn check_size<To>(size: usize) {
+	let size_to = size_of::<To>();
+
+	if size != size_to {
+		let errkind = TransmuteErrKind::size_mismatch(size, size_to);
+
+		errkind.unwrap();
+	}
+}
+
+/// Data dimension check, `size` must be a multiple of the size of `To`,
+/// returns the number of `To` elements.
+#[cfg(any(test, feature = "try_transmute"))]
+#[track_caller]
+#[inline]
+const fn scaled_len<To>(size: usize) -> usize {
+	match crate::bytes::check_slice_len::<To>(size) {
+		Ok(len) => len,
+		Err(errkind) => errkind.unwrap(),
 	}
 }
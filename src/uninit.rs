@@ -0,0 +1,77 @@
+//! Constant helpers for working with arrays and slices of `MaybeUninit<T>`.
+//!
+//! (The conversions are performed by `transmute_unchecked`, so with the
+//! `assert_transmute_mode` build flag and `debug_assertions` the data
+//! dimensions are additionally checked.)
+
+use crate::raw::transmute_unchecked;
+use core::mem::MaybeUninit;
+
+/// Creates a new array of `MaybeUninit<T>` items, in an uninitialized state.
+#[inline]
+pub const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+	[const { MaybeUninit::uninit() }; N]
+}
+
+/// Extracts the values from an array of `MaybeUninit` containers.
+///
+/// # Safety
+///
+/// It is up to the caller to guarantee that all elements of the array are
+/// in an initialized state.
+#[track_caller]
+#[inline]
+pub const unsafe fn array_assume_init<T, const N: usize>(array: [MaybeUninit<T>; N]) -> [T; N] {
+	unsafe { transmute_unchecked(array) }
+}
+
+/// Gets a shared reference to the contained values of a slice of `MaybeUninit` containers.
+///
+/// # Safety
+///
+/// It is up to the caller to guarantee that all elements of the slice are
+/// in an initialized state.
+#[track_caller]
+#[inline]
+pub const unsafe fn slice_assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+	// This is allowed because `MaybeUninit<T>` has the same layout as `T`.
+	unsafe { transmute_unchecked(slice) }
+}
+
+/// Gets a mutable reference to the contained values of a slice of `MaybeUninit` containers.
+///
+/// # Safety
+///
+/// It is up to the caller to guarantee that all elements of the slice are
+/// in an initialized state.
+#[track_caller]
+#[inline]
+pub const unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+	// This is allowed because `MaybeUninit<T>` has the same layout as `T`.
+	unsafe { transmute_unchecked(slice) }
+}
+
+/// Copies the elements from `src` to `dst`, returning a mutable reference
+/// to the now initialized contents of `dst`.
+///
+/// The function panics if the two slices have different lengths.
+#[track_caller]
+#[inline]
+pub const fn write_slice<'a, T>(dst: &'a mut [MaybeUninit<T>], src: &[T]) -> &'a mut [T]
+where
+	T: Copy,
+{
+	assert!(
+		dst.len() == src.len(),
+		"write_slice: destination and source slices have different lengths"
+	);
+
+	let mut i = 0;
+	while i < src.len() {
+		dst[i] = MaybeUninit::new(src[i]);
+		i += 1;
+	}
+
+	// All elements of `dst` have just been initialized.
+	unsafe { slice_assume_init_mut(dst) }
+}
@@ -0,0 +1,406 @@
+//! Typed views over byte buffers made of fixed-size records.
+//!
+//! ```rust
+//! use cluFullTransmute::bytes::AnyBitPattern;
+//! use cluFullTransmute::bytes::NoPadding;
+//! use cluFullTransmute::bytes::Zeroable;
+//! use cluFullTransmute::endian::U16Le;
+//! use cluFullTransmute::endian::U32Le;
+//! use cluFullTransmute::view::ByteView;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy)]
+//! struct Record {
+//! 	time: U32Le,
+//! 	value: U16Le,
+//! }
+//!
+//! unsafe impl NoPadding for Record {}
+//! unsafe impl AnyBitPattern for Record {}
+//! unsafe impl Zeroable for Record {}
+//!
+//! // header: u16 count, records: (u32 time, u16 value) * count
+//! let data: [u8; 14] = [2, 0, 1, 0, 0, 0, 10, 0, 2, 0, 0, 0, 20, 0];
+//!
+//! let (count, records) = ByteView::<Record>::split_header::<U16Le>(&data).unwrap();
+//! assert_eq!(count.get() as usize, records.len());
+//!
+//! let values: Vec<u16> = records.iter().map(|a| a.value.get()).collect();
+//! assert_eq!(values, [10, 20]);
+//! ```
+
+use crate::bytes::AnyBitPattern;
+use crate::bytes::NoPadding;
+use crate::bytes::check_slice_len;
+use crate::err::TransmuteErr;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::align_of;
+use core::mem::size_of;
+
+/// Reads the `index` record of `T` by copy, the record must be in bounds.
+#[inline]
+const unsafe fn read_record<T>(bytes: &[u8], index: usize) -> T {
+	unsafe { core::ptr::read_unaligned(bytes.as_ptr().add(index * size_of::<T>()) as *const T) }
+}
+
+/// Returns the byte offset of the `index` record of `T` in the buffer of `len`
+/// bytes starting at `ptr`, if the record is in bounds and aligned for `T`.
+#[inline]
+fn record_offset<T>(ptr: *const u8, len: usize, index: usize) -> Option<usize> {
+	if index >= len / size_of::<T>() {
+		return None;
+	}
+
+	let offset = index * size_of::<T>();
+	match (ptr.addr() + offset).is_multiple_of(align_of::<T>()) {
+		true => Some(offset),
+		false => None,
+	}
+}
+
+/// Returns a pointer to the `index` record of `T`, if the record is in bounds
+/// and aligned for `T`.
+#[inline]
+fn record_ptr<T>(bytes: &[u8], index: usize) -> Option<*const T> {
+	let offset = record_offset::<T>(bytes.as_ptr(), bytes.len(), index)?;
+
+	Some(unsafe { bytes.as_ptr().add(offset) } as *const T)
+}
+
+/// Returns a mutable pointer to the `index` record of `T`, if the record is in
+/// bounds and aligned for `T`.
+///
+/// (the pointer is derived from the mutable borrow, so writing through it is allowed)
+#[inline]
+fn record_ptr_mut<T>(bytes: &mut [u8], index: usize) -> Option<*mut T> {
+	let offset = record_offset::<T>(bytes.as_ptr(), bytes.len(), index)?;
+
+	Some(unsafe { bytes.as_mut_ptr().add(offset) } as *mut T)
+}
+
+/// Splits the header `H` (read by copy) from the front of the buffer.
+fn split_header<H>(bytes: &[u8]) -> Result<(H, &[u8]), TransmuteErr<&[u8]>>
+where
+	H: AnyBitPattern,
+{
+	match bytes.split_at_checked(size_of::<H>()) {
+		Some((header, records)) => match crate::bytes::read_from(header) {
+			Ok(header) => Ok((header, records)),
+			Err(e) => Err(TransmuteErr::new(e.kind(), bytes)),
+		},
+		None => Err(TransmuteErr::size_exceeded(
+			bytes.len(),
+			size_of::<H>(),
+			bytes,
+		)),
+	}
+}
+
+/// A view of a byte buffer as consecutive records of `T`.
+///
+/// The buffer does not have to be aligned for `T`: records can always be
+/// read by copy, references to records are only available for aligned records.
+pub struct ByteView<'a, T> {
+	bytes: &'a [u8],
+
+	_pp: PhantomData<&'a [T]>,
+}
+
+impl<'a, T> ByteView<'a, T>
+where
+	T: AnyBitPattern,
+{
+	/// Create a view, the length of the buffer must be a multiple of the size of `T`.
+	#[inline]
+	pub const fn new(bytes: &'a [u8]) -> Result<Self, TransmuteErr<&'a [u8]>> {
+		if let Err(e) = check_slice_len::<T>(bytes.len()) {
+			return Err(TransmuteErr::new(e, bytes));
+		}
+
+		Ok(Self {
+			bytes,
+			_pp: PhantomData,
+		})
+	}
+
+	/// Split the header `H` (read by copy) from the front of the buffer and
+	/// create a view of the remaining records.
+	#[inline]
+	pub fn split_header<H>(bytes: &'a [u8]) -> Result<(H, Self), TransmuteErr<&'a [u8]>>
+	where
+		H: AnyBitPattern,
+	{
+		let (header, records) = split_header::<H>(bytes)?;
+
+		Ok((header, Self::new(records)?))
+	}
+
+	/// Number of records.
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.bytes.len() / size_of::<T>()
+	}
+
+	/// Whether there are no records.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.bytes.is_empty()
+	}
+
+	/// Get the underlying bytes.
+	#[inline]
+	pub const fn as_bytes(&self) -> &'a [u8] {
+		self.bytes
+	}
+
+	/// Get a link to the record, `None` if the record is out of bounds
+	/// or the buffer is not aligned for `T` (see [ByteView::read]).
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<&'a T> {
+		record_ptr::<T>(self.bytes, index).map(|a| unsafe { &*a })
+	}
+
+	/// Read a copy of the record, `None` if the record is out of bounds.
+	#[inline]
+	pub const fn read(&self, index: usize) -> Option<T> {
+		match index < self.len() {
+			true => Some(unsafe { read_record(self.bytes, index) }),
+			false => None,
+		}
+	}
+
+	/// View the records as a slice, the buffer must be aligned for `T`.
+	#[inline]
+	pub fn as_slice(&self) -> Result<&'a [T], TransmuteErr<&'a [u8]>> {
+		let ptr = self.bytes.as_ptr();
+		if let Err(e) = crate::bytes::check_align::<T>(ptr) {
+			return Err(TransmuteErr::new(e, self.bytes));
+		}
+
+		Ok(unsafe { core::slice::from_raw_parts(ptr as *const T, self.len()) })
+	}
+
+	/// Iterate over copies of the records.
+	#[inline]
+	pub const fn iter(&self) -> Iter<'a, T> {
+		Iter {
+			bytes: self.bytes,
+			_pp: PhantomData,
+		}
+	}
+}
+
+impl<'a, T> Clone for ByteView<'a, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, T> Copy for ByteView<'a, T> {}
+
+impl<'a, T> Debug for ByteView<'a, T>
+where
+	T: AnyBitPattern + Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<'a, T> IntoIterator for ByteView<'a, T>
+where
+	T: AnyBitPattern,
+{
+	type Item = T;
+	type IntoIter = Iter<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An iterator over copies of the records of a byte buffer.
+pub struct Iter<'a, T> {
+	bytes: &'a [u8],
+
+	_pp: PhantomData<&'a [T]>,
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bytes: self.bytes,
+			_pp: PhantomData,
+		}
+	}
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+	T: AnyBitPattern,
+{
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (record, bytes) = self.bytes.split_at_checked(size_of::<T>())?;
+		self.bytes = bytes;
+
+		Some(unsafe { read_record(record, 0) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.bytes.len() / size_of::<T>();
+
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T>
+where
+	T: AnyBitPattern,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let index = self.bytes.len().checked_sub(size_of::<T>())?;
+		let (bytes, record) = self.bytes.split_at(index);
+		self.bytes = bytes;
+
+		Some(unsafe { read_record(record, 0) })
+	}
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> where T: AnyBitPattern {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> where T: AnyBitPattern {}
+
+/// A mutable view of a byte buffer as consecutive records of `T`.
+///
+/// The buffer does not have to be aligned for `T`: records can always be
+/// read and written by copy, references to records are only available for
+/// aligned records.
+pub struct ByteViewMut<'a, T> {
+	bytes: &'a mut [u8],
+
+	_pp: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T> ByteViewMut<'a, T>
+where
+	T: AnyBitPattern + NoPadding,
+{
+	/// Create a view, the length of the buffer must be a multiple of the size of `T`.
+	#[inline]
+	pub const fn new(bytes: &'a mut [u8]) -> Result<Self, TransmuteErr<&'a mut [u8]>> {
+		if let Err(e) = check_slice_len::<T>(bytes.len()) {
+			return Err(TransmuteErr::new(e, bytes));
+		}
+
+		Ok(Self {
+			bytes,
+			_pp: PhantomData,
+		})
+	}
+
+	/// Split the header `H` (read by copy) from the front of the buffer and
+	/// create a view of the remaining records.
+	#[inline]
+	pub fn split_header<H>(bytes: &'a mut [u8]) -> Result<(H, Self), TransmuteErr<&'a mut [u8]>>
+	where
+		H: AnyBitPattern,
+	{
+		let header = match split_header::<H>(bytes) {
+			Ok((header, _)) => header,
+			Err(e) => return Err(TransmuteErr::new(e.kind(), bytes)),
+		};
+
+		let (_, records) = bytes.split_at_mut(size_of::<H>());
+		Ok((header, Self::new(records)?))
+	}
+
+	/// Number of records.
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.bytes.len() / size_of::<T>()
+	}
+
+	/// Whether there are no records.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.bytes.is_empty()
+	}
+
+	/// Get an immutable view.
+	#[inline]
+	pub const fn as_view(&self) -> ByteView<'_, T> {
+		ByteView {
+			bytes: self.bytes,
+			_pp: PhantomData,
+		}
+	}
+
+	/// Get a link to the record, `None` if the record is out of bounds
+	/// or the buffer is not aligned for `T` (see [ByteViewMut::read]).
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<&T> {
+		record_ptr::<T>(self.bytes, index).map(|a| unsafe { &*a })
+	}
+
+	/// Get a mutable link to the record, `None` if the record is out of bounds
+	/// or the buffer is not aligned for `T` (see [ByteViewMut::write]).
+	#[inline]
+	pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		record_ptr_mut::<T>(self.bytes, index).map(|a| unsafe { &mut *a })
+	}
+
+	/// Read a copy of the record, `None` if the record is out of bounds.
+	#[inline]
+	pub const fn read(&self, index: usize) -> Option<T> {
+		match index < self.len() {
+			true => Some(unsafe { read_record(self.bytes, index) }),
+			false => None,
+		}
+	}
+
+	/// Write the record by copy, `None` if the record is out of bounds.
+	#[inline]
+	pub const fn write(&mut self, index: usize, value: T) -> Option<()> {
+		match index < self.len() {
+			true => {
+				unsafe {
+					let ptr = self.bytes.as_mut_ptr().add(index * size_of::<T>());
+
+					core::ptr::write_unaligned(ptr as *mut T, value);
+				}
+
+				Some(())
+			}
+			false => None,
+		}
+	}
+
+	/// Iterate over copies of the records.
+	#[inline]
+	pub const fn iter(&self) -> Iter<'_, T> {
+		Iter {
+			bytes: self.bytes,
+			_pp: PhantomData,
+		}
+	}
+}
+
+impl<'a, T> Debug for ByteViewMut<'a, T>
+where
+	T: AnyBitPattern + NoPadding + Debug,
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+		Debug::fmt(&self.as_view(), f)
+	}
+}
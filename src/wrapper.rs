@@ -0,0 +1,224 @@
+//! Casting between `#[repr(transparent)]` newtypes and the types they wrap.
+//!
+//! ```rust
+//! use cluFullTransmute::wrapper::TransparentWrapper;
+//!
+//! #[repr(transparent)]
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Meters(u32);
+//!
+//! unsafe impl TransparentWrapper<u32> for Meters {}
+//!
+//! let data: &[u32] = &[1, 2, 3];
+//! let meters: &[Meters] = Meters::wrap_slice(data);
+//!
+//! assert_eq!(meters, [Meters(1), Meters(2), Meters(3)]);
+//! assert_eq!(Meters::peel(Meters(4)), 4);
+//! ```
+
+use crate::raw::transmute_unchecked;
+use core::mem::align_of;
+use core::mem::size_of;
+
+/// Implements `TransparentWrapper` for a `#[repr(transparent)]` struct.
+///
+/// If the struct has more than one field, the wrapped (non-ZST) field must be
+/// marked with `#[transparent_wrapper]`, all other fields must be `PhantomData`.
+///
+/// ```rust
+/// use cluFullTransmute::wrapper::TransparentWrapper;
+/// use core::marker::PhantomData;
+///
+/// #[repr(transparent)]
+/// #[derive(TransparentWrapper)]
+/// struct Tagged<T> {
+/// 	_tag: PhantomData<T>,
+/// 	#[transparent_wrapper]
+/// 	value: u16,
+/// }
+///
+/// assert_eq!(Tagged::<u8>::wrap(3).value, 3);
+/// ```
+///
+/// The marked field must be the non-ZST field:
+///
+/// ```rust,compile_fail
+/// use cluFullTransmute::wrapper::TransparentWrapper;
+/// use core::marker::PhantomData;
+///
+/// #[repr(transparent)]
+/// #[derive(TransparentWrapper)]
+/// struct Bad {
+/// 	#[transparent_wrapper]
+/// 	_tag: PhantomData<u64>,
+/// 	value: u64,
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use cluFullTransmute_derive::TransparentWrapper;
+
+/// A type that is a `#[repr(transparent)]` wrapper around `Inner`.
+///
+/// All functions are associated functions (not methods), so they do not
+/// conflict with the methods of the wrapper itself: `Meters::wrap(1)`.
+///
+/// # Safety
+///
+/// `Self` must have the same layout as `Inner` (e.g. `#[repr(transparent)]`
+/// with `Inner` as the only non-ZST field), and any valid value of `Inner` must
+/// be a valid value of `Self` and vice versa.
+pub unsafe trait TransparentWrapper<Inner>: Sized {
+	/// Checking the layout of the wrapper at compile time
+	#[doc(hidden)]
+	const TYPE_LAYOUT_MATCH_ASSERT: () = [()][
+		// If you read this in the error logs, then the wrapper does not have
+		// the same size and alignment as the wrapped type.
+		(size_of::<Self>() != size_of::<Inner>() || align_of::<Self>() != align_of::<Inner>())
+			as usize
+	];
+
+	/// Wrap a value.
+	#[inline]
+	fn wrap(s: Inner) -> Self {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Wrap a reference.
+	#[inline]
+	fn wrap_ref(s: &Inner) -> &Self {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Wrap a mutable reference.
+	#[inline]
+	fn wrap_mut(s: &mut Inner) -> &mut Self {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Wrap a slice.
+	#[inline]
+	fn wrap_slice(s: &[Inner]) -> &[Self] {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Wrap a mutable slice.
+	#[inline]
+	fn wrap_slice_mut(s: &mut [Inner]) -> &mut [Self] {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Unwrap a value.
+	#[inline]
+	fn peel(s: Self) -> Inner {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Unwrap a reference.
+	#[inline]
+	fn peel_ref(s: &Self) -> &Inner {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Unwrap a mutable reference.
+	#[inline]
+	fn peel_mut(s: &mut Self) -> &mut Inner {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Unwrap a slice.
+	#[inline]
+	fn peel_slice(s: &[Self]) -> &[Inner] {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Unwrap a mutable slice.
+	#[inline]
+	fn peel_slice_mut(s: &mut [Self]) -> &mut [Inner] {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Wrap a boxed value without reallocation.
+	#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+	#[cfg(feature = "alloc")]
+	#[inline]
+	fn wrap_box(s: alloc::boxed::Box<Inner>) -> alloc::boxed::Box<Self> {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Unwrap a boxed value without reallocation.
+	#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+	#[cfg(feature = "alloc")]
+	#[inline]
+	fn peel_box(s: alloc::boxed::Box<Self>) -> alloc::boxed::Box<Inner> {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		unsafe { transmute_unchecked(s) }
+	}
+
+	/// Wrap all values of a vector without reallocation.
+	#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+	#[cfg(feature = "alloc")]
+	#[inline]
+	fn wrap_vec(s: alloc::vec::Vec<Inner>) -> alloc::vec::Vec<Self> {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		// The layout of `Vec<T>` is not guaranteed to be the same for different `T`,
+		// so the vector is disassembled into raw parts and assembled again.
+		let mut s = core::mem::ManuallyDrop::new(s);
+		let (ptr, len, capacity) = (s.as_mut_ptr(), s.len(), s.capacity());
+
+		unsafe { alloc::vec::Vec::from_raw_parts(ptr as *mut Self, len, capacity) }
+	}
+
+	/// Unwrap all values of a vector without reallocation.
+	#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+	#[cfg(feature = "alloc")]
+	#[inline]
+	fn peel_vec(s: alloc::vec::Vec<Self>) -> alloc::vec::Vec<Inner> {
+		#[allow(clippy::let_unit_value)]
+		let _constant_checking_of_wrapper_layout = Self::TYPE_LAYOUT_MATCH_ASSERT;
+
+		// The layout of `Vec<T>` is not guaranteed to be the same for different `T`,
+		// so the vector is disassembled into raw parts and assembled again.
+		let mut s = core::mem::ManuallyDrop::new(s);
+		let (ptr, len, capacity) = (s.as_mut_ptr(), s.len(), s.capacity());
+
+		unsafe { alloc::vec::Vec::from_raw_parts(ptr as *mut Inner, len, capacity) }
+	}
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::aligned::Align1;
+use cluFullTransmute::aligned::Align8;
+use cluFullTransmute::aligned::Align64;
+use cluFullTransmute::aligned::AlignOf;
+use cluFullTransmute::aligned::AlignedBytes;
+use cluFullTransmute::aligned::RawBytes;
+use cluFullTransmute::bytes::AnyBitPattern;
+use cluFullTransmute::bytes::NoPadding;
+use cluFullTransmute::bytes::Zeroable;
+use cluFullTransmute::include_aligned_bytes;
+use core::mem::align_of;
+use core::mem::size_of;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Header {
+	magic: u64,
+	len: u32,
+	flags: u32,
+}
+
+unsafe impl NoPadding for Header {}
+unsafe impl AnyBitPattern for Header {}
+unsafe impl Zeroable for Header {}
+
+const HEADER: Header = Header {
+	magic: 0xDEADBEEF,
+	len: 10,
+	flags: 1,
+};
+
+#[test]
+fn aligned_layout() {
+	assert_eq!(align_of::<Align1>(), 1);
+	assert_eq!(align_of::<Align64>(), 64);
+	assert_eq!(size_of::<Align64>(), 0);
+	assert_eq!(align_of::<AlignOf<u128>>(), align_of::<u128>());
+
+	assert_eq!(align_of::<RawBytes<4>>(), 1);
+	assert_eq!(align_of::<RawBytes<8, u64>>(), align_of::<u64>());
+	assert_eq!(size_of::<AlignedBytes<64, Align64>>(), 64);
+	assert_eq!(align_of::<AlignedBytes<64, Align64>>(), 64);
+}
+
+#[test]
+fn aligned_value() {
+	const DATA: AlignedBytes<16, AlignOf<Header>> = AlignedBytes::from_value(HEADER);
+
+	assert_eq!(DATA.as_value::<Header>(), &HEADER);
+	assert_eq!(DATA.into_value::<Header>(), HEADER);
+	assert_eq!(DATA.as_bytes()[..8], 0xDEADBEEFu64.to_ne_bytes());
+
+	let mut data = DATA;
+	data.as_value_mut::<Header>().flags = 2;
+	assert_eq!(data.as_value::<[u32; 4]>()[3], 2);
+
+	let data: RawBytes<16, Align8> = unsafe { cluFullTransmute::try_transmute(HEADER) }.unwrap();
+	let header: Header = unsafe { cluFullTransmute::try_transmute(data) }.unwrap();
+	assert_eq!(header, HEADER);
+}
+
+#[test]
+fn aligned_include() {
+	static DATA: &AlignedBytes<16, Align8> = include_aligned_bytes!(Align8, "data/u64x2.bin");
+
+	assert_eq!(
+		DATA.as_value::<[u64; 2]>(),
+		&[u64::from_le(1), u64::from_le(2)]
+	);
+	assert!(
+		(DATA.as_bytes().as_ptr() as usize).is_multiple_of(8),
+		"the embedded data must be aligned"
+	);
+}
+
+#[test]
+fn aligned_include_transmuted() {
+	use cluFullTransmute::include_transmuted;
+	use cluFullTransmute::include_transmuted_slice;
+
+	#[repr(C)]
+	#[derive(Debug, Clone, Copy, PartialEq)]
+	struct Record {
+		key: u32,
+		value: u32,
+	}
+
+	unsafe impl AnyBitPattern for Record {}
+	unsafe impl Zeroable for Record {}
+
+	static TABLE: &[u16; 8] = include_transmuted!("data/u64x2.bin" as [u16; 8]);
+	static RECORDS: &[Record] = include_transmuted_slice!("data/u64x2.bin" as Record);
+
+	assert_eq!(TABLE[4], u16::from_le(2));
+	assert_eq!(
+		RECORDS,
+		[
+			Record {
+				key: u32::from_le(1),
+				value: 0
+			},
+			Record {
+				key: u32::from_le(2),
+				value: 0
+			},
+		]
+	);
+
+	let mut data = RawBytes::<16, u64>::from_value(*TABLE);
+	data.as_slice_mut::<u64>()[1] = 3;
+	assert_eq!(data.as_slice::<u64>(), [u64::from_le(1), 3]);
+}
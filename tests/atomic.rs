@@ -0,0 +1,153 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::atomic::AtomicCell;
+use cluFullTransmute::bytes::NoPadding;
+use std::sync::atomic::Ordering;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct State {
+	generation: u32,
+	readers: u16,
+	flags: u16,
+}
+
+unsafe impl NoPadding for State {}
+
+#[test]
+fn atomic_cell_sizes() {
+	let a = AtomicCell::new(true);
+	assert!(a.swap(false, Ordering::Relaxed));
+	assert!(!a.load(Ordering::Relaxed));
+
+	let a = AtomicCell::new([1u8, 2]);
+	a.store([3, 4], Ordering::Relaxed);
+	assert_eq!(a.load(Ordering::Relaxed), [3, 4]);
+
+	let a = AtomicCell::new('a');
+	assert_eq!(
+		a.compare_exchange('b', 'c', Ordering::Relaxed, Ordering::Relaxed),
+		Err('a')
+	);
+	assert_eq!(
+		a.compare_exchange('a', 'c', Ordering::Relaxed, Ordering::Relaxed),
+		Ok('a')
+	);
+	assert_eq!(a.into_inner(), 'c');
+
+	let mut a = AtomicCell::<State>::default();
+	a.get_mut().flags = 1;
+	assert_eq!(
+		a.load(Ordering::Relaxed),
+		State {
+			generation: 0,
+			readers: 0,
+			flags: 1
+		}
+	);
+	assert_eq!(
+		format!("{a:?}"),
+		"AtomicCell(State { generation: 0, readers: 0, flags: 1 })"
+	);
+}
+
+#[test]
+fn atomic_cell_threads() {
+	static STATE: AtomicCell<State> = AtomicCell::new(State {
+		generation: 0,
+		readers: 0,
+		flags: 0,
+	});
+
+	std::thread::scope(|s| {
+		for _ in 0..4 {
+			s.spawn(|| {
+				for _ in 0..100 {
+					let mut current = STATE.load(Ordering::Acquire);
+					loop {
+						let next = State {
+							generation: current.generation + 1,
+							readers: current.readers + 1,
+							flags: current.flags,
+						};
+
+						match STATE.compare_exchange(
+							current,
+							next,
+							Ordering::AcqRel,
+							Ordering::Acquire,
+						) {
+							Ok(_) => break,
+							Err(a) => current = a,
+						}
+					}
+				}
+			});
+		}
+	});
+
+	assert_eq!(
+		STATE.load(Ordering::Acquire),
+		State {
+			generation: 400,
+			readers: 400,
+			flags: 0
+		}
+	);
+}
+
+#[test]
+fn atomic_views() {
+	use cluFullTransmute::atomic;
+	use std::sync::atomic::AtomicU8;
+
+	let mut value: u32 = 1;
+	let a = atomic::as_atomic_u32(&mut value).unwrap();
+	assert_eq!(a.fetch_add(1, Ordering::Relaxed), 1);
+	assert_eq!(value, 2);
+
+	let mut values: [u16; 3] = [1, 2, 3];
+	let a = atomic::slice_as_atomic_u16(&mut values).unwrap();
+	a[2].store(30, Ordering::Relaxed);
+	assert_eq!(values, [1, 2, 30]);
+
+	let ring: [AtomicU8; 4] = [0, 1, 2, 3].map(AtomicU8::new);
+	ring[0].store(4, Ordering::Relaxed);
+	assert_eq!(unsafe { atomic::atomic_slice_as_u8(&ring) }, [4, 1, 2, 3]);
+
+	#[cfg(target_has_atomic = "64")]
+	{
+		let mut values: [u64; 2] = [0; 2];
+		match atomic::slice_as_atomic_u64(&mut values) {
+			Ok(a) => {
+				a[1].store(u64::MAX, Ordering::Relaxed);
+				assert_eq!(values, [0, u64::MAX]);
+			}
+			// (e.g. x86: `align_of::<u64>() == 4`, `align_of::<AtomicU64>() == 8`)
+			Err(e) => assert!(e.is_align_mismatch()),
+		}
+
+		// The reverse view is always allowed.
+		let counters: [std::sync::atomic::AtomicU64; 2] =
+			[1, 2].map(std::sync::atomic::AtomicU64::new);
+		counters[1].store(u64::MAX, Ordering::Relaxed);
+		assert_eq!(
+			unsafe { atomic::atomic_slice_as_u64(&counters) },
+			[1, u64::MAX]
+		);
+	}
+}
+
+#[test]
+fn atomic_views_const() {
+	const fn counter(value: &mut usize) -> &std::sync::atomic::AtomicUsize {
+		match cluFullTransmute::atomic::as_atomic_usize(value) {
+			Ok(a) => a,
+			Err(e) => e.unwrap(),
+		}
+	}
+
+	let mut value = 0;
+	counter(&mut value).fetch_add(5, Ordering::Relaxed);
+	assert_eq!(value, 5);
+}
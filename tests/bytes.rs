@@ -0,0 +1,68 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::bytes;
+use cluFullTransmute::bytes::AnyBitPattern;
+use cluFullTransmute::bytes::NoPadding;
+use cluFullTransmute::bytes::Zeroable;
+use cluFullTransmute::err::TransmuteErrKind;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Header {
+	magic: u32,
+	len: u16,
+	flags: u16,
+}
+
+unsafe impl NoPadding for Header {}
+unsafe impl AnyBitPattern for Header {}
+unsafe impl Zeroable for Header {}
+
+const HEADER: Header = Header {
+	magic: 0xDEADBEEF,
+	len: 10,
+	flags: 1,
+};
+
+#[test]
+fn bytes_roundtrip() {
+	let array: [u8; 8] = bytes::to_bytes(HEADER);
+	assert_eq!(bytes::as_bytes(&HEADER), array);
+
+	let header: Header = bytes::read_from(&array).unwrap();
+	assert_eq!(header, HEADER);
+
+	let mut header = HEADER;
+	bytes::as_bytes_mut(&mut header)[4..6].copy_from_slice(&20u16.to_ne_bytes());
+	assert_eq!(header.len, 20);
+}
+
+#[test]
+fn bytes_from_bytes() {
+	let data: [u32; 2] = [0xDEADBEEF, u32::from_ne_bytes([10, 0, 1, 0])];
+	let data: &[u8] = bytes::as_bytes(&data);
+
+	let header: &Header = bytes::from_bytes(data).unwrap();
+	assert_eq!(header.magic, 0xDEADBEEF);
+
+	let err = bytes::from_bytes::<Header>(&data[..4]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_mismatch(4, 8));
+
+	let err = bytes::from_bytes::<u16>(&data[1..3]).unwrap_err();
+	assert!(err.is_align_mismatch());
+	assert_eq!(*err.as_data(), &data[1..3]);
+
+	let err = bytes::read_from::<u64>(&data[1..]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_mismatch(7, 8));
+}
+
+#[test]
+fn bytes_eq_hash() {
+	const HASH: u64 = bytes::bytes_hash(&0u8);
+
+	const { assert!(bytes::bytes_eq(&HEADER, &HEADER)) };
+	assert!(!bytes::bytes_eq(&1u32, &2u32));
+	// FNV-1a 64 of a single zero byte.
+	assert_eq!(HASH, 0xaf63bd4c8601b7df);
+	assert_eq!(bytes::bytes_hash(&HEADER), bytes::bytes_hash(&HEADER));
+}
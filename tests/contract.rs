@@ -0,0 +1,186 @@
+#![cfg(feature = "contract")]
+
+use std::collections::hash_map::DefaultHasher;
+use std::future::Future;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::pin::pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use cluFullTransmute::contract::TransmuteContract;
+use cluFullTransmute::contract::TrustedContract;
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Hash)]
+struct Meters(u32);
+
+// Every `u32` is a valid `Meters`.
+unsafe impl TrustedContract<Meters> for u32 {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Config {
+	data: TransmuteContract<u32, u32>,
+	flag: bool,
+}
+
+#[test]
+fn contract_derive_copy_default() {
+	let a = Config::default();
+	let b = a;
+
+	assert_eq!(a, b);
+	assert_eq!(*a.data.as_in(), 0);
+	assert_eq!(TransmuteContract::<u32, u32>::from(7).into(), 7);
+}
+
+#[test]
+fn contract_derive_default_trusted() {
+	#[derive(Debug, Clone, Copy, Default, PartialEq)]
+	struct Route {
+		distance: TransmuteContract<u32, Meters>,
+		flag: bool,
+	}
+
+	let a = Route::default();
+	assert_eq!(a.distance.into(), Meters(0));
+
+	let b = Route {
+		distance: TransmuteContract::from(7),
+		flag: true,
+	};
+	assert_eq!(*b.distance.as_out(), Meters(7));
+}
+
+#[test]
+fn contract_as_out_view() {
+	let a: TransmuteContract<u32, Meters> = unsafe { TransmuteContract::new_unchecked(10) };
+	let b: TransmuteContract<u32, Meters> = unsafe { TransmuteContract::new_unchecked(10) };
+
+	assert_eq!(format!("{a}"), "10");
+	assert_eq!(format!("{a:?}"), "10");
+	assert_eq!(format!("{:?}", a.debug_out()), "Meters(10)");
+	assert_eq!(format!("{:?}", a.as_out_view()), "Meters(10)");
+	assert_eq!(a.as_out_view(), b.as_out_view());
+
+	let hash = |value: &dyn Fn(&mut DefaultHasher)| {
+		let mut hasher = DefaultHasher::new();
+		value(&mut hasher);
+		hasher.finish()
+	};
+	assert_eq!(
+		hash(&|h| a.as_out_view().hash(h)),
+		hash(&|h| Meters(10).hash(h))
+	);
+	assert_eq!(a.as_out_view().into_contract().into(), Meters(10));
+}
+
+#[test]
+fn contract_iterator() {
+	let mut iter: TransmuteContract<core::ops::Range<u16>, core::ops::Range<u16>> =
+		TransmuteContract::from(0..4);
+
+	assert_eq!(iter.len(), 4);
+	assert_eq!(iter.next_back(), Some(3));
+	assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[test]
+fn contract_future() {
+	let future: TransmuteContract<_, _> = TransmuteContract::from(core::future::ready(5u8));
+	let future = pin!(future);
+
+	let mut cx = Context::from_waker(Waker::noop());
+	assert_eq!(future.poll(&mut cx), Poll::Ready(5));
+}
+
+#[test]
+fn contract_drop_as_out() {
+	use std::sync::atomic::AtomicUsize;
+	use std::sync::atomic::Ordering;
+
+	static DROP_IN: AtomicUsize = AtomicUsize::new(0);
+	static DROP_OUT: AtomicUsize = AtomicUsize::new(0);
+
+	#[repr(transparent)]
+	struct Handle(usize);
+
+	impl Drop for Handle {
+		fn drop(&mut self) {
+			DROP_IN.fetch_add(1, Ordering::SeqCst);
+		}
+	}
+
+	#[repr(transparent)]
+	struct Owner(usize);
+
+	impl Drop for Owner {
+		fn drop(&mut self) {
+			DROP_OUT.fetch_add(self.0, Ordering::SeqCst);
+		}
+	}
+
+	let contract: TransmuteContract<Handle, Owner> =
+		unsafe { TransmuteContract::new_unchecked(Handle(10)) };
+	drop(contract);
+	assert_eq!(DROP_IN.load(Ordering::SeqCst), 1);
+	assert_eq!(DROP_OUT.load(Ordering::SeqCst), 0);
+
+	let contract: TransmuteContract<Handle, Owner> =
+		unsafe { TransmuteContract::new_unchecked(Handle(20)) };
+	let contract = contract.drop_as_out();
+	assert_eq!(contract.as_in().0, 20);
+	assert_eq!(contract.as_out().0, 20);
+	drop(contract);
+	assert_eq!(DROP_IN.load(Ordering::SeqCst), 1);
+	assert_eq!(DROP_OUT.load(Ordering::SeqCst), 20);
+
+	let contract: TransmuteContract<Handle, Owner> =
+		unsafe { TransmuteContract::new_unchecked(Handle(30)) };
+	let handle = contract.drop_as_out().release_indata();
+	assert_eq!(DROP_OUT.load(Ordering::SeqCst), 20);
+	drop(handle);
+	assert_eq!(DROP_IN.load(Ordering::SeqCst), 2);
+
+	// Replacing the data through `OUT` keeps the drop policy.
+	let contract: TransmuteContract<Handle, Owner> =
+		unsafe { TransmuteContract::new_unchecked(Handle(1)) };
+	let mut contract = contract.drop_as_out();
+	contract.as_mut_in().0 = 2;
+	*contract.as_mut_out() = Owner(3);
+	assert_eq!(DROP_OUT.load(Ordering::SeqCst), 22);
+	drop(contract);
+	assert_eq!(DROP_IN.load(Ordering::SeqCst), 2);
+	assert_eq!(DROP_OUT.load(Ordering::SeqCst), 25);
+}
+
+#[test]
+fn contract_pin() {
+	use std::marker::PhantomPinned;
+
+	#[derive(Default)]
+	struct NotUnpin(u32, PhantomPinned);
+
+	let contract: TransmuteContract<NotUnpin, NotUnpin> = TransmuteContract::default();
+	let mut contract = pin!(contract);
+
+	unsafe { contract.as_mut().as_pin_in().get_unchecked_mut().0 = 5 };
+	assert_eq!(contract.as_ref().as_pin_ref_out().0, 5);
+
+	let future: TransmuteContract<_, _> = TransmuteContract::from(async { 10u8 });
+	let future = pin!(future);
+
+	let mut cx = Context::from_waker(Waker::noop());
+	assert_eq!(future.as_pin_out().poll(&mut cx), Poll::Ready(10));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn contract_pin_box() {
+	let future: TransmuteContract<_, _> = TransmuteContract::from(async { 10u8 });
+	let mut future = Box::pin(future).into_pin_out();
+
+	let mut cx = Context::from_waker(Waker::noop());
+	assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(10));
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::err::TransmuteErrKind;
+use cluFullTransmute::try_transmute_copy;
+use cluFullTransmute::try_transmute_copy_or_panic;
+use cluFullTransmute::try_transmute_copy_unaligned;
+
+#[repr(C)]
+struct Header {
+	magic: u32,
+	len: u16,
+	flags: u16,
+}
+
+const TABLE: [u64; 2] = [u64::MAX, 1];
+
+#[test]
+fn copy_borrowed_data() {
+	const HEADER: Header = Header {
+		magic: 0xDEADBEEF,
+		len: 10,
+		flags: 1,
+	};
+	const MAGIC: u32 = unsafe { try_transmute_copy_or_panic(&HEADER) };
+	const PAIR: [u32; 2] = unsafe { try_transmute_copy_or_panic(&TABLE) };
+
+	assert_eq!(MAGIC, 0xDEADBEEF);
+	assert_eq!(PAIR, [u32::MAX, u32::MAX]);
+	assert_eq!((HEADER.len, HEADER.flags), (10, 1));
+}
+
+#[test]
+fn copy_errors() {
+	let err = unsafe { try_transmute_copy::<u32, u64>(&1) }.unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_exceeded(4, 8));
+	assert!(err.is_size_exceeded());
+	assert!(!err.is_size_mismatch());
+	assert_eq!(**err.as_data(), 1);
+
+	let bytes: [u8; 8] = 7u64.to_ne_bytes();
+	let err = unsafe { try_transmute_copy::<[u8; 8], u64>(&bytes) }.unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::align_mismatch(1, 8));
+	assert!(err.is_align_mismatch());
+
+	let value: u64 = unsafe { try_transmute_copy_unaligned(&bytes) }.unwrap();
+	assert_eq!(value, 7);
+}
+
+#[cfg(feature = "error_details")]
+#[test]
+fn copy_error_description() {
+	let kind = TransmuteErrKind::size_exceeded(4, 8);
+	assert_eq!(
+		kind.as_description().as_str(),
+		"Invalid transmute: attempted to reinterpret type A (4 bytes) as incompatible type B (8 bytes). Type B must not be larger than type A."
+	);
+
+	let kind = TransmuteErrKind::align_mismatch(1, 8);
+	assert_eq!(
+		kind.as_description().as_str(),
+		"Invalid transmute: attempted to reinterpret data with alignment 1 as incompatible type B with alignment 8. The alignment of type B must not be larger than the alignment of the data."
+	);
+}
+
+#[test]
+#[should_panic]
+fn copy_or_panic_unaligned() {
+	let bytes: [u8; 4] = [0; 4];
+
+	let _e: u32 = unsafe { try_transmute_copy_or_panic(&bytes) };
+}
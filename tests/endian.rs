@@ -0,0 +1,88 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::endian;
+use cluFullTransmute::endian::BigEndian;
+use cluFullTransmute::endian::NativeEndian;
+
+#[test]
+fn endian_from_bytes() {
+	const BYTES: [u8; 8] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+
+	const BE: [u32; 2] = endian::from_be_bytes(BYTES);
+	const LE: [u16; 4] = endian::from_le_bytes(BYTES);
+
+	assert_eq!(BE, [0x00010203, 0x04050607]);
+	assert_eq!(LE, [0x0100, 0x0302, 0x0504, 0x0706]);
+	assert_eq!(
+		endian::from_be_bytes::<u64, 8, 1>(BYTES),
+		[0x0001020304050607]
+	);
+	assert_eq!(endian::from_le_bytes::<u8, 8, 8>(BYTES), BYTES);
+
+	let native: [u32; 2] = endian::from_bytes::<NativeEndian, _, 8, 2>(BYTES);
+	assert_eq!(
+		native,
+		[
+			u32::from_ne_bytes([0, 1, 2, 3]),
+			u32::from_ne_bytes([4, 5, 6, 7])
+		]
+	);
+}
+
+#[test]
+fn endian_to_bytes() {
+	let bytes: [u8; 8] = endian::to_be_bytes([1.5f32, -2.0]);
+	assert_eq!(bytes[..4], 1.5f32.to_be_bytes());
+	assert_eq!(bytes[4..], (-2.0f32).to_be_bytes());
+
+	let bytes: [u8; 4] = endian::to_le_bytes([0x0102i16, -2]);
+	assert_eq!(bytes, [0x02, 0x01, 0xFE, 0xFF]);
+
+	let values: [i16; 2] =
+		endian::from_bytes::<BigEndian, _, 4, 2>(endian::to_be_bytes([7i16, -7]));
+	assert_eq!(values, [7, -7]);
+}
+
+#[test]
+#[should_panic]
+fn endian_size_mismatch() {
+	let _e: [u32; 2] = endian::from_be_bytes([0u8; 7]);
+}
+
+#[test]
+fn endian_int_types() {
+	use cluFullTransmute::endian::I32Le;
+	use cluFullTransmute::endian::U16Be;
+	use cluFullTransmute::endian::U64Be;
+
+	#[repr(C)]
+	#[derive(Debug, Clone, Copy, PartialEq)]
+	struct Header {
+		kind: U16Be,
+		flags: U16Be,
+		offset: I32Le,
+		id: U64Be,
+	}
+
+	assert_eq!(core::mem::align_of::<Header>(), 1);
+	assert_eq!(core::mem::size_of::<Header>(), 16);
+
+	const BYTES: [u8; 16] = [
+		0x00, 0x01, 0x80, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0, 0, 0, 0x01, 0x02,
+	];
+	let mut header: Header = unsafe { cluFullTransmute::try_transmute_or_panic(BYTES) };
+
+	assert_eq!(header.kind.get(), 1);
+	assert_eq!(header.flags, 0x8000);
+	assert_eq!(header.offset.get(), -2);
+	assert_eq!(header.id.get(), 0x0102);
+
+	header.kind.set(0x0A0B);
+	const ID: U64Be = U64Be::new(3);
+	header.id = ID;
+
+	let bytes: [u8; 16] = unsafe { cluFullTransmute::try_transmute_or_panic(header) };
+	assert_eq!(bytes[..2], [0x0A, 0x0B]);
+	assert_eq!(bytes[8..], 3u64.to_be_bytes());
+	assert_eq!(format!("{:?}", header.offset), "-2");
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::erased::ErasedValue;
+use cluFullTransmute::erased::SendErasedValue;
+use cluFullTransmute::err::TransmuteErrKind;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::mpsc;
+
+#[test]
+fn erased_downcast() {
+	let mut value = ErasedValue::<32, u64>::new(String::from("Test"));
+	assert!(value.is::<String>());
+	assert!(value.downcast_ref::<&str>().is_none());
+
+	value.downcast_mut::<String>().unwrap().push('1');
+	assert_eq!(value.downcast_ref::<String>().unwrap(), "Test1");
+
+	let value = value.downcast::<Vec<u8>>().unwrap_err();
+	assert_eq!(value.downcast::<String>().unwrap(), "Test1");
+
+	let value = ErasedValue::<1, u8>::new(());
+	assert_eq!(value.downcast::<()>().ok(), Some(()));
+}
+
+#[test]
+fn erased_drop() {
+	let data = Rc::new(10);
+
+	let value = ErasedValue::<16>::new(data.clone());
+	assert_eq!(Rc::strong_count(&data), 2);
+	drop(value);
+	assert_eq!(Rc::strong_count(&data), 1);
+
+	let value = ErasedValue::<16>::new(data.clone());
+	let data2 = value.downcast::<Rc<i32>>().unwrap();
+	assert_eq!(Rc::strong_count(&data), 2);
+	drop(data2);
+	assert_eq!(Rc::strong_count(&data), 1);
+}
+
+#[test]
+fn erased_try_new() {
+	let err = ErasedValue::<4, u32>::try_new(1u64).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_exceeded(4, 8));
+	assert_eq!(err.into_data(), 1);
+
+	let err = ErasedValue::<8, u8>::try_new(1u64).unwrap_err();
+	assert_eq!(
+		err.kind(),
+		TransmuteErrKind::align_mismatch(1, core::mem::align_of::<u64>())
+	);
+
+	let value = ErasedValue::<8, u64>::try_new(1u16).unwrap();
+	assert_eq!(value.downcast::<u16>().ok(), Some(1));
+}
+
+#[test]
+fn erased_send() {
+	let data = Arc::new(10);
+	let (tx, rx) = mpsc::channel::<SendErasedValue<32, u64>>();
+
+	let thread = std::thread::spawn(move || {
+		let mut values = Vec::new();
+		while let Ok(value) = rx.recv() {
+			values.push(value);
+		}
+		values
+	});
+	tx.send(SendErasedValue::new(String::from("Test"))).unwrap();
+	tx.send(SendErasedValue::new(data.clone())).unwrap();
+	tx.send(SendErasedValue::new((1u32, 'a'))).unwrap();
+	drop(tx);
+
+	let mut values = thread.join().unwrap().into_iter();
+	let value = values.next().unwrap();
+	assert!(value.is::<String>());
+	assert_eq!(value.downcast::<String>().unwrap(), "Test");
+
+	let value = values.next().unwrap();
+	assert_eq!(Arc::strong_count(&data), 2);
+	drop(value);
+	assert_eq!(Arc::strong_count(&data), 1);
+
+	let value = values.next().unwrap().downcast::<u32>().unwrap_err();
+	assert_eq!(value.downcast_ref::<(u32, char)>(), Some(&(1, 'a')));
+	assert_eq!(
+		value.into_erased().downcast::<(u32, char)>().ok(),
+		Some((1, 'a'))
+	);
+
+	let err = SendErasedValue::<4, u32>::try_new(1u64).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_exceeded(4, 8));
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use cluFullTransmute::bytes;
+use cluFullTransmute::bytes::AnyBitPattern;
+use cluFullTransmute::bytes::NoPadding;
+use cluFullTransmute::bytes::Zeroable;
+use cluFullTransmute::err::TransmuteErrKind;
+use cluFullTransmute::io;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FileHeader {
+	magic: [u8; 4],
+	version: u16,
+	count: u16,
+}
+
+unsafe impl NoPadding for FileHeader {}
+unsafe impl AnyBitPattern for FileHeader {}
+unsafe impl Zeroable for FileHeader {}
+
+#[test]
+fn io_roundtrip() {
+	let header = FileHeader {
+		magic: *b"CLU\0",
+		version: 1,
+		count: 3,
+	};
+	let records: [u32; 3] = [10, 20, 30];
+
+	let mut file: Vec<u8> = Vec::new();
+	io::write_transmuted(&mut file, &header).unwrap();
+	for a in &records {
+		io::write_transmuted(&mut file, a).unwrap();
+	}
+	assert_eq!(file.len(), 8 + 12);
+
+	let mut reader: &[u8] = &file;
+	let header2: FileHeader = io::read_transmuted(&mut reader).unwrap();
+	assert_eq!(header2, header);
+
+	let mut records2: Vec<u32> = vec![0; header2.count as usize];
+	io::read_slice_into(&mut reader, &mut records2).unwrap();
+	assert_eq!(records2, records);
+
+	let err = io::read_transmuted::<u32, _>(&mut reader).unwrap_err();
+	assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn io_error_invalid_data() {
+	fn parse(data: &[u8]) -> std::io::Result<&FileHeader> {
+		Ok(bytes::from_bytes(data)?)
+	}
+
+	let err = parse(&[0; 3]).unwrap_err();
+	assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+	assert_eq!(
+		err.get_ref().unwrap().downcast_ref::<TransmuteErrKind>(),
+		Some(&TransmuteErrKind::size_mismatch(3, 8))
+	);
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::bytes::AnyBitPattern;
+use cluFullTransmute::bytes::NoPadding;
+use cluFullTransmute::bytes::Zeroable;
+use cluFullTransmute::err::TransmuteErrKind;
+use cluFullTransmute::layout::FingerprintHasher;
+use cluFullTransmute::layout::LayoutFingerprint;
+use cluFullTransmute::layout::Versioned;
+use core::mem::offset_of;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RecordV1 {
+	id: u32,
+	value: u32,
+}
+
+impl LayoutFingerprint for RecordV1 {
+	const LAYOUT_FINGERPRINT: u64 = FingerprintHasher::new()
+		.write_field::<u32>("id", offset_of!(RecordV1, id), "u32")
+		.write_field::<u32>("value", offset_of!(RecordV1, value), "u32")
+		.finish();
+}
+
+/// The fields of `RecordV1` in a different order.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RecordV2 {
+	value: u32,
+	id: u32,
+}
+
+impl LayoutFingerprint for RecordV2 {
+	const LAYOUT_FINGERPRINT: u64 = FingerprintHasher::new()
+		.write_field::<u32>("value", offset_of!(RecordV2, value), "u32")
+		.write_field::<u32>("id", offset_of!(RecordV2, id), "u32")
+		.finish();
+}
+
+unsafe impl NoPadding for RecordV1 {}
+unsafe impl AnyBitPattern for RecordV1 {}
+unsafe impl Zeroable for RecordV1 {}
+unsafe impl NoPadding for RecordV2 {}
+unsafe impl AnyBitPattern for RecordV2 {}
+unsafe impl Zeroable for RecordV2 {}
+
+#[test]
+fn layout_versioned_roundtrip() {
+	let data = Versioned::new(RecordV1 { id: 1, value: 2 });
+	assert!(data.is_current());
+	assert_eq!(data.fingerprint(), RecordV1::LAYOUT_FINGERPRINT);
+
+	let bytes: &[u8] = data.as_bytes();
+	assert_eq!(bytes.len(), 8 + 8);
+	assert_eq!(bytes[..8], RecordV1::LAYOUT_FINGERPRINT.to_le_bytes());
+
+	assert_eq!(
+		Versioned::<RecordV1>::read_from(bytes),
+		Ok(RecordV1 { id: 1, value: 2 })
+	);
+	assert_eq!(
+		Versioned::<RecordV1>::from_bytes(bytes),
+		Ok(&RecordV1 { id: 1, value: 2 })
+	);
+	assert_eq!(data.into_value(), Ok(RecordV1 { id: 1, value: 2 }));
+}
+
+#[test]
+fn layout_versioned_mismatch() {
+	assert_ne!(RecordV1::LAYOUT_FINGERPRINT, RecordV2::LAYOUT_FINGERPRINT);
+
+	let data = Versioned::new(RecordV1 { id: 1, value: 2 });
+	let err = Versioned::<RecordV2>::read_from(data.as_bytes()).unwrap_err();
+	assert!(err.is_layout_mismatch());
+	assert_eq!(
+		err.kind(),
+		TransmuteErrKind::layout_mismatch(
+			RecordV1::LAYOUT_FINGERPRINT,
+			RecordV2::LAYOUT_FINGERPRINT
+		)
+	);
+
+	let err = Versioned::<RecordV1>::read_from(&data.as_bytes()[1..]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_mismatch(15, 16));
+}
+
+#[cfg(feature = "error_details")]
+#[test]
+fn layout_error_description() {
+	let kind = TransmuteErrKind::layout_mismatch(0x1, 0xabcdef0123456789);
+
+	assert_eq!(
+		kind.as_description().as_str(),
+		"Invalid transmute: the stored layout fingerprint (0x0000000000000001) does not match the layout fingerprint of type B (0xabcdef0123456789). The layout of type B has changed since the data was written."
+	);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn layout_derive() {
+	mod v1 {
+		#[repr(C)]
+		#[derive(cluFullTransmute::layout::LayoutFingerprint)]
+		pub struct Record {
+			pub id: u32,
+			pub value: u32,
+		}
+	}
+
+	mod v2 {
+		#[repr(C)]
+		#[derive(cluFullTransmute::layout::LayoutFingerprint)]
+		pub struct Record {
+			pub value: u32,
+			pub id: u32,
+		}
+	}
+
+	mod v3 {
+		#[repr(C)]
+		#[derive(cluFullTransmute::layout::LayoutFingerprint)]
+		pub struct Record {
+			pub id: u32,
+			pub value: i32,
+		}
+	}
+
+	#[repr(C)]
+	#[derive(LayoutFingerprint)]
+	struct Pair<T>(T, T);
+
+	use cluFullTransmute::layout::LayoutFingerprint;
+
+	assert_ne!(
+		v1::Record::LAYOUT_FINGERPRINT,
+		v2::Record::LAYOUT_FINGERPRINT
+	);
+	assert_ne!(
+		v1::Record::LAYOUT_FINGERPRINT,
+		v3::Record::LAYOUT_FINGERPRINT
+	);
+	assert_ne!(
+		Pair::<u16>::LAYOUT_FINGERPRINT,
+		Pair::<u32>::LAYOUT_FINGERPRINT
+	);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn layout_derive_nested() {
+	mod v1 {
+		#[repr(C)]
+		#[derive(cluFullTransmute::layout::LayoutFingerprint)]
+		pub struct Inner {
+			pub a: u32,
+			pub b: u32,
+		}
+
+		#[repr(C)]
+		#[derive(cluFullTransmute::layout::LayoutFingerprint)]
+		pub struct Outer {
+			pub inner: Inner,
+			pub c: u64,
+		}
+	}
+
+	/// `v1` with the fields of `Inner` in a different order.
+	mod v2 {
+		#[repr(C)]
+		#[derive(cluFullTransmute::layout::LayoutFingerprint)]
+		pub struct Inner {
+			pub b: u32,
+			pub a: u32,
+		}
+
+		#[repr(C)]
+		#[derive(cluFullTransmute::layout::LayoutFingerprint)]
+		pub struct Outer {
+			pub inner: Inner,
+			pub c: u64,
+		}
+	}
+
+	use cluFullTransmute::layout::LayoutFingerprint;
+
+	assert_ne!(v1::Inner::LAYOUT_FINGERPRINT, v2::Inner::LAYOUT_FINGERPRINT);
+	assert_ne!(v1::Outer::LAYOUT_FINGERPRINT, v2::Outer::LAYOUT_FINGERPRINT);
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "compatible_stdapi")]
+
+use cluFullTransmute::mem;
+use core::mem::MaybeUninit;
+use core::num::NonZeroU32;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Header {
+	magic: u32,
+	len: u16,
+	flags: u16,
+}
+
+#[test]
+fn mem_transmute_copy() {
+	let header = Header {
+		magic: 0xDEADBEEF,
+		len: 10,
+		flags: 1,
+	};
+
+	let magic: u32 = unsafe { mem::transmute_copy(&header) };
+	assert_eq!(magic, 0xDEADBEEF);
+
+	// the alignment of `u64` is larger than the alignment of `[u8; 8]`.
+	let bytes: [u8; 8] = 7u64.to_ne_bytes();
+	let value: u64 = unsafe { mem::transmute_copy(&bytes) };
+	assert_eq!(value, 7);
+
+	// the source is not consumed.
+	assert_eq!(header.len, 10);
+}
+
+#[test]
+#[should_panic]
+fn mem_transmute_copy_larger() {
+	let _e: u64 = unsafe { mem::transmute_copy(&1u32) };
+}
+
+#[test]
+fn mem_zeroed() {
+	const ZERO: (u32, Option<NonZeroU32>, [bool; 2]) = mem::zeroed();
+	assert_eq!(ZERO, (0, None, [false; 2]));
+
+	let mut value: Option<&u8> = Some(&1);
+	assert_eq!(mem::take_zeroed(&mut value), Some(&1));
+	assert_eq!(value, None);
+
+	// `core::mem` items are still available.
+	assert_eq!(mem::size_of::<Header>(), 8);
+	assert_eq!(mem::replace(&mut 1, 2), 1);
+}
+
+#[test]
+fn mem_uninit_array() {
+	const ARRAY: [u16; 4] = {
+		let mut array: [MaybeUninit<u16>; 4] = mem::uninit_array();
+
+		let mut i = 0;
+		while i < array.len() {
+			array[i] = MaybeUninit::new(i as u16 * 2);
+			i += 1;
+		}
+
+		unsafe { mem::array_assume_init(array) }
+	};
+
+	assert_eq!(ARRAY, [0, 2, 4, 6]);
+}
+
+#[cfg(feature = "try_transmute")]
+#[test]
+fn mem_zeroed_any_bit_pattern() {
+	use cluFullTransmute::bytes::AnyBitPattern;
+	use cluFullTransmute::endian::U32Le;
+
+	// `AnyBitPattern` implies `Zeroable`.
+	fn zeroed_record<T: AnyBitPattern>() -> T {
+		mem::zeroed()
+	}
+
+	assert_eq!(zeroed_record::<[U32Le; 2]>(), [U32Le::new(0); 2]);
+}
@@ -0,0 +1,130 @@
+// File mappings are not supported by miri.
+#![cfg(all(feature = "mmap", unix, not(miri)))]
+
+use cluFullTransmute::bytes::AnyBitPattern;
+use cluFullTransmute::bytes::NoPadding;
+use cluFullTransmute::bytes::Zeroable;
+use cluFullTransmute::err::TransmuteErrKind;
+use cluFullTransmute::io;
+use cluFullTransmute::layout::FingerprintHasher;
+use cluFullTransmute::layout::LayoutFingerprint;
+use cluFullTransmute::layout::fingerprint_header;
+use cluFullTransmute::mmap::MappedView;
+use cluFullTransmute::mmap::MappedViewMut;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Record {
+	key: u32,
+	offset: u32,
+}
+
+unsafe impl NoPadding for Record {}
+unsafe impl AnyBitPattern for Record {}
+unsafe impl Zeroable for Record {}
+
+impl LayoutFingerprint for Record {
+	const LAYOUT_FINGERPRINT: u64 = FingerprintHasher::new()
+		.write_field::<u32>("key", core::mem::offset_of!(Record, key), "u32")
+		.write_field::<u32>("offset", core::mem::offset_of!(Record, offset), "u32")
+		.finish();
+}
+
+const RECORDS: [Record; 3] = [
+	Record { key: 1, offset: 0 },
+	Record { key: 2, offset: 64 },
+	Record {
+		key: 3,
+		offset: 128,
+	},
+];
+
+/// A file in the temporary directory, removed on drop.
+struct TempFile(PathBuf);
+
+impl TempFile {
+	fn new(name: &str, header: &[u8], records: &[Record]) -> Self {
+		let path =
+			std::env::temp_dir().join(format!("cluFullTransmute-{}-{}", name, std::process::id()));
+
+		let mut file = std::fs::File::create(&path).unwrap();
+		file.write_all(header).unwrap();
+		for a in records {
+			io::write_transmuted(&mut file, a).unwrap();
+		}
+
+		Self(path)
+	}
+}
+
+impl Drop for TempFile {
+	fn drop(&mut self) {
+		let _e = std::fs::remove_file(&self.0);
+	}
+}
+
+fn kind(e: std::io::Error) -> TransmuteErrKind {
+	assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+
+	*e.get_ref()
+		.unwrap()
+		.downcast_ref::<TransmuteErrKind>()
+		.unwrap()
+}
+
+#[test]
+fn mmap_view() {
+	let file = TempFile::new("view", &[], &RECORDS);
+
+	let view = unsafe { MappedView::<Record>::open(&file.0) }.unwrap();
+	assert_eq!(view.len(), 3);
+	assert_eq!(view.as_slice(), RECORDS);
+
+	let view = unsafe { MappedView::<u16>::open(&file.0) }.unwrap();
+	assert_eq!(view.len(), 12);
+
+	let err = unsafe { MappedView::<[u8; 5]>::open(&file.0) }.unwrap_err();
+	assert_eq!(kind(err), TransmuteErrKind::size_mismatch(24, 5));
+
+	let empty = TempFile::new("empty", &[], &[]);
+	let view = unsafe { MappedView::<Record>::open(&empty.0) }.unwrap();
+	assert!(view.is_empty());
+	assert_eq!(view.as_slice(), []);
+}
+
+#[test]
+fn mmap_view_mut() {
+	let file = TempFile::new("view_mut", &[], &RECORDS);
+
+	{
+		let mut view = unsafe { MappedViewMut::<Record>::open(&file.0) }.unwrap();
+		view[1].offset = 100;
+		view.flush().unwrap();
+	}
+
+	let data = std::fs::read(&file.0).unwrap();
+	assert_eq!(data[12..16], 100u32.to_ne_bytes());
+}
+
+#[test]
+fn mmap_view_versioned() {
+	let file = TempFile::new("versioned", &fingerprint_header::<Record>(), &RECORDS);
+
+	let view = unsafe { MappedView::<Record>::open_versioned(&file.0) }.unwrap();
+	assert_eq!(*view, RECORDS);
+
+	let err = unsafe { MappedView::<[u32; 2]>::open_versioned(&file.0) }.unwrap_err();
+	assert_eq!(
+		kind(err),
+		TransmuteErrKind::layout_mismatch(
+			Record::LAYOUT_FINGERPRINT,
+			<[u32; 2]>::LAYOUT_FINGERPRINT
+		)
+	);
+
+	let short = TempFile::new("short", &[1, 2, 3], &[]);
+	let err = unsafe { MappedView::<Record>::open_versioned(&short.0) }.unwrap_err();
+	assert_eq!(kind(err), TransmuteErrKind::size_exceeded(3, 8));
+}
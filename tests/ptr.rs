@@ -0,0 +1,119 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::declare_slice_dst;
+use cluFullTransmute::err::TransmuteErrKind;
+use cluFullTransmute::ptr;
+
+declare_slice_dst! {
+	#[derive(Debug)]
+	struct Packet {
+		header: u32,
+		body: [u16],
+	}
+}
+
+#[repr(C, align(8))]
+struct Aligned<const N: usize>([u8; N]);
+
+#[test]
+fn ptr_str() {
+	assert_eq!(ptr::str_as_bytes("Test"), b"Test");
+	assert_eq!(ptr::bytes_as_str(b"Test"), Ok("Test"));
+
+	let err = ptr::bytes_as_str(b"Te\xffst").unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::invalid_utf8(2));
+	assert_eq!(err.into_data(), b"Te\xffst");
+
+	let mut data = *b"test";
+	ptr::bytes_as_str_mut(&mut data)
+		.unwrap()
+		.make_ascii_uppercase();
+	assert_eq!(&data, b"TEST");
+
+	let mut data = [0xc3u8];
+	assert!(
+		ptr::bytes_as_str_mut(&mut data)
+			.unwrap_err()
+			.is_invalid_utf8()
+	);
+}
+
+#[test]
+fn ptr_slice_cast() {
+	let values: &[u32] = &[0x0201, 0x0403];
+	let halves: &[u16] = ptr::slice_cast(values).unwrap();
+	assert_eq!(halves, &[0x0201u16.to_le(), 0, 0x0403u16.to_le(), 0][..]);
+
+	let bytes: &[u8] = ptr::slice_cast(values).unwrap();
+	assert_eq!(bytes.len(), 8);
+
+	let empty: &[u32] = &[];
+	assert_eq!(ptr::slice_cast::<u32, u64>(empty), Ok(&[][..]));
+
+	// Data dimension check
+	let err = ptr::slice_cast::<u32, u64>(&values[..1]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_mismatch(4, 8));
+
+	// Data alignment check
+	let data = Aligned([0u8; 8]);
+	assert!(
+		ptr::slice_cast::<u8, u32>(&data.0[1..5])
+			.unwrap_err()
+			.is_align_mismatch()
+	);
+
+	let mut values = [0u32; 2];
+	let bytes: &mut [u8] = ptr::slice_cast_mut(&mut values).unwrap();
+	bytes.fill(0xff);
+	assert_eq!(values, [u32::MAX; 2]);
+}
+
+#[test]
+fn ptr_dst_from_bytes() {
+	let mut data = Aligned([0u8; 8]);
+	data.0[..4].copy_from_slice(&7u32.to_ne_bytes());
+	data.0[4..6].copy_from_slice(&1u16.to_ne_bytes());
+	data.0[6..8].copy_from_slice(&2u16.to_ne_bytes());
+
+	let packet: &Packet = ptr::dst_from_bytes(&data.0).unwrap();
+	assert_eq!(packet.header, 7);
+	assert_eq!(&packet.body, &[1, 2]);
+	assert_eq!(size_of_val(packet), 8);
+
+	let packet: &Packet = ptr::dst_from_bytes(&data.0[..4]).unwrap();
+	assert!(packet.body.is_empty());
+
+	let packet: &mut Packet = ptr::dst_from_bytes_mut(&mut data.0).unwrap();
+	packet.body[1] = 3;
+	assert_eq!(&data.0[6..8], &3u16.to_ne_bytes());
+
+	// Data dimension check
+	let err = ptr::dst_from_bytes::<Packet>(&data.0[..3]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_exceeded(3, 4));
+
+	let err = ptr::dst_from_bytes::<Packet>(&data.0[..5]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_mismatch(1, 2));
+
+	// (the tail of one `u16` leaves the struct without its trailing padding)
+	let err = ptr::dst_from_bytes::<Packet>(&data.0[..6]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_mismatch(6, 8));
+
+	// Data alignment check
+	let data = Aligned([0u8; 16]);
+	assert!(
+		ptr::dst_from_bytes::<Packet>(&data.0[2..10])
+			.unwrap_err()
+			.is_align_mismatch()
+	);
+}
+
+#[cfg(feature = "error_details")]
+#[test]
+fn ptr_error_description() {
+	let kind = TransmuteErrKind::invalid_utf8(3);
+
+	assert_eq!(
+		kind.as_description().as_str(),
+		"Invalid transmute: the data is not valid UTF-8 (valid up to 3 bytes)."
+	);
+}
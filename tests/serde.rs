@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+
+use cluFullTransmute::contract::TransmuteContract;
+use cluFullTransmute::contract::TrustedContract;
+use cluFullTransmute::err::TransmuteErr;
+use cluFullTransmute::err::TransmuteErrKind;
+
+#[repr(transparent)]
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+// Every `u16` is a valid `Port`.
+unsafe impl TrustedContract<Port> for u16 {}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Config {
+	port: TransmuteContract<u16, u16>,
+	name: String,
+}
+
+#[test]
+fn serde_contract_transparent() {
+	let config = Config {
+		port: TransmuteContract::from(8080),
+		name: "test".into(),
+	};
+
+	let json = serde_json::to_string(&config).unwrap();
+	assert_eq!(json, r#"{"port":8080,"name":"test"}"#);
+
+	let config2: Config = serde_json::from_str(&json).unwrap();
+	assert_eq!(config, config2);
+	assert_eq!(config2.port.into(), 8080);
+}
+
+#[test]
+fn serde_contract_trusted() {
+	let port: TransmuteContract<u16, Port> = TransmuteContract::from(443);
+
+	let json = serde_json::to_string(&port).unwrap();
+	assert_eq!(json, "443");
+
+	let port2: TransmuteContract<u16, Port> = serde_json::from_str(&json).unwrap();
+	assert_eq!(port, port2);
+	assert_eq!(port2.into(), Port(443));
+}
+
+#[test]
+fn serde_err() {
+	let err = unsafe { cluFullTransmute::try_transmute::<[u8; 3], u32>([1, 2, 3]) }.unwrap_err();
+
+	let json = serde_json::to_string(&err).unwrap();
+	assert_eq!(
+		json,
+		r#"{"data":[1,2,3],"kind":{"SizeMismatch":{"atype":3,"btype":4}}}"#
+	);
+
+	let err2: TransmuteErr<[u8; 3]> = serde_json::from_str(&json).unwrap();
+	assert_eq!(err, err2);
+	assert_eq!(err2.kind(), TransmuteErrKind::size_mismatch(3, 4));
+}
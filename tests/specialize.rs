@@ -0,0 +1,62 @@
+#![cfg(feature = "specialize")]
+
+use cluFullTransmute::specialize;
+use cluFullTransmute::specialize::cast_if_same;
+use cluFullTransmute::specialize::cast_mut_if_same;
+use cluFullTransmute::specialize::cast_ref_if_same;
+
+#[test]
+fn specialize_cast_if_same() {
+	assert_eq!(cast_if_same::<u8, u8>(10), Ok(10));
+	assert_eq!(cast_if_same::<u8, i8>(10), Err(10));
+	assert_eq!(
+		cast_if_same::<String, String>("a".into()),
+		Ok(String::from("a"))
+	);
+
+	let data: &str = "Test";
+	assert_eq!(cast_ref_if_same::<str, str>(data), Ok("Test"));
+	assert_eq!(cast_ref_if_same::<str, [u8]>(data), Err("Test"));
+
+	let mut data: Vec<u8> = vec![1, 2];
+	if let Ok(a) = cast_mut_if_same::<Vec<u8>, Vec<u8>>(&mut data) {
+		a.push(3);
+	}
+	assert!(cast_mut_if_same::<Vec<u8>, Vec<i8>>(&mut data).is_err());
+	assert_eq!(data, [1, 2, 3]);
+}
+
+#[test]
+fn specialize_macro() {
+	fn sum<T: 'static>(values: &[T]) -> u64 {
+		specialize!(ref values, {
+			[u8] => |a| a.iter().map(|a| *a as u64).sum(),
+			[u32] => |a| a.iter().map(|a| *a as u64).sum(),
+			else => |_a| 0,
+		})
+	}
+
+	assert_eq!(sum::<u8>(&[1, 2, 3]), 6);
+	assert_eq!(sum::<u32>(&[10, 20]), 30);
+	assert_eq!(sum::<i8>(&[1]), 0);
+
+	// The value is moved to the selected branch.
+	let name = specialize!(String::from("Test"), {
+		&str => |a| a.to_string(),
+		String => |a| a,
+		else => |_a| String::new(),
+	});
+	assert_eq!(name, "Test");
+
+	fn clear<T: 'static>(value: &mut T) {
+		specialize!(ref mut value, {
+			String => |a| a.clear(),
+			Vec<u8> => |a| a.clear(),
+			else => |_a| {},
+		})
+	}
+
+	let mut name = name;
+	clear(&mut name);
+	assert_eq!(name, "");
+}
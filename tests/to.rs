@@ -0,0 +1,62 @@
+#![cfg(all(feature = "to", feature = "try_transmute"))]
+#![cfg_attr(feature = "nightly_const_trait", feature(const_trait_impl))]
+
+use cluFullTransmute::to::ToTransmute;
+use cluFullTransmute::to::ToTransmuteRef;
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(u32);
+
+#[test]
+fn to_transmute_ref() {
+	let mut value: u32 = 10;
+
+	assert_eq!(unsafe { value.transmute_ref::<Meters>() }, &Meters(10));
+	unsafe { value.transmute_mut::<Meters>() }.0 = 20;
+	assert_eq!(value, 20);
+}
+
+#[cfg(feature = "contract")]
+#[test]
+fn to_into_contract() {
+	let value: u32 = 20;
+
+	let contract = unsafe { value.into_contract::<Meters>() };
+	assert_eq!(contract.into(), Meters(20));
+}
+
+#[test]
+fn to_transmute_slice() {
+	let mut data: [u32; 3] = [1, 2, 3];
+
+	let meters: &[Meters] = unsafe { data.as_slice().transmute_slice() };
+	assert_eq!(meters, [Meters(1), Meters(2), Meters(3)]);
+
+	let meters: &mut [Meters] = unsafe { data.as_mut_slice().transmute_slice_mut() };
+	meters[1].0 = 5;
+	assert_eq!(data, [1, 5, 3]);
+
+	let bytes: &[u8] = unsafe { "test".transmute_slice() };
+	assert_eq!(bytes, b"test");
+	assert_eq!(unsafe { "test".transmute_ref::<[u8; 4]>() }, b"test");
+}
+
+#[test]
+#[should_panic]
+fn to_transmute_slice_mismatch() {
+	let data: [u8; 3] = [1, 2, 3];
+
+	let _e: &[u16] = unsafe { data.as_slice().transmute_slice() };
+}
+
+#[cfg(feature = "nightly_const_trait")]
+#[test]
+fn to_transmute_const() {
+	const fn to_u32(bytes: [u8; 4]) -> u32 {
+		unsafe { bytes.try_transmute_or_panic::<u32>() }
+	}
+	const VALUE: u32 = to_u32([1, 0, 0, 0]);
+
+	assert_eq!(VALUE, u32::from_ne_bytes([1, 0, 0, 0]));
+}
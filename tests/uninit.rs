@@ -0,0 +1,51 @@
+#![cfg(feature = "compatible_stdapi")]
+
+use cluFullTransmute::uninit;
+use core::mem::MaybeUninit;
+
+#[derive(Debug, PartialEq)]
+struct NotCopy(u32);
+
+const fn make_array<const N: usize>() -> [NotCopy; N] {
+	let mut array: [MaybeUninit<NotCopy>; N] = uninit::uninit_array();
+
+	let mut i = 0;
+	while i < N {
+		array[i] = MaybeUninit::new(NotCopy(i as u32));
+		i += 1;
+	}
+
+	unsafe { uninit::array_assume_init(array) }
+}
+
+#[test]
+fn uninit_array_const() {
+	const ARRAY: [NotCopy; 3] = make_array();
+
+	assert_eq!(ARRAY, [NotCopy(0), NotCopy(1), NotCopy(2)]);
+}
+
+#[test]
+fn uninit_slice() {
+	let mut array: [MaybeUninit<u8>; 4] = uninit::uninit_array();
+
+	let init: &mut [u8] = uninit::write_slice(&mut array, &[1, 2, 3, 4]);
+	init[0] = 10;
+
+	assert_eq!(
+		unsafe { uninit::slice_assume_init_ref(&array) },
+		[10, 2, 3, 4]
+	);
+
+	let init: &mut [u8] = unsafe { uninit::slice_assume_init_mut(&mut array) };
+	init[3] = 40;
+	assert_eq!(unsafe { uninit::array_assume_init(array) }, [10, 2, 3, 40]);
+}
+
+#[test]
+#[should_panic]
+fn uninit_write_slice_len_mismatch() {
+	let mut array: [MaybeUninit<u8>; 4] = uninit::uninit_array();
+
+	uninit::write_slice(&mut array, &[1, 2, 3]);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "try_transmute")]
+
+use cluFullTransmute::bytes::AnyBitPattern;
+use cluFullTransmute::bytes::NoPadding;
+use cluFullTransmute::bytes::Zeroable;
+use cluFullTransmute::err::TransmuteErrKind;
+use cluFullTransmute::view::ByteView;
+use cluFullTransmute::view::ByteViewMut;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Record {
+	time: u32,
+	value: u32,
+}
+
+unsafe impl NoPadding for Record {}
+unsafe impl AnyBitPattern for Record {}
+unsafe impl Zeroable for Record {}
+
+#[repr(C, align(8))]
+struct Aligned([u8; 24]);
+
+const DATA: Aligned =
+	Aligned(unsafe { cluFullTransmute::try_transmute_or_panic([1u32, 10, 2, 20, 3, 30]) });
+
+#[test]
+fn view_records() {
+	let view: ByteView<Record> = ByteView::new(&DATA.0).unwrap();
+
+	assert_eq!(view.len(), 3);
+	assert_eq!(view.get(1), Some(&Record { time: 2, value: 20 }));
+	assert_eq!(view.get(3), None);
+	assert_eq!(view.read(2), Some(Record { time: 3, value: 30 }));
+	assert_eq!(view.as_slice().unwrap().len(), 3);
+	assert_eq!(
+		view.iter().rev().map(|a| a.value).collect::<Vec<_>>(),
+		[30, 20, 10]
+	);
+
+	let err = ByteView::<Record>::new(&DATA.0[..20]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_mismatch(20, 8));
+}
+
+#[test]
+fn view_unaligned() {
+	let mut buf = Aligned([0; 24]);
+	buf.0[1..17].copy_from_slice(&DATA.0[..16]);
+
+	let view: ByteView<Record> = ByteView::new(&buf.0[1..17]).unwrap();
+
+	assert_eq!(view.get(0), None);
+	assert_eq!(view.read(0), Some(Record { time: 1, value: 10 }));
+	assert!(view.as_slice().unwrap_err().is_align_mismatch());
+	assert_eq!(view.iter().count(), 2);
+}
+
+#[test]
+fn view_split_header() {
+	let (count, records) = ByteView::<Record>::split_header::<u32>(&DATA.0[..20]).unwrap();
+	assert_eq!(count, 1);
+	assert_eq!(records.len(), 2);
+	assert_eq!(records.read(0), Some(Record { time: 10, value: 2 }));
+
+	let err = ByteView::<Record>::split_header::<u64>(&DATA.0[..4]).unwrap_err();
+	assert_eq!(err.kind(), TransmuteErrKind::size_exceeded(4, 8));
+}
+
+#[test]
+fn view_mut() {
+	let mut data = Aligned(DATA.0);
+	let mut view: ByteViewMut<Record> = ByteViewMut::new(&mut data.0).unwrap();
+
+	view.get_mut(0).unwrap().value = 100;
+	assert_eq!(view.write(2, Record { time: 4, value: 40 }), Some(()));
+	assert_eq!(view.write(3, Record { time: 5, value: 50 }), None);
+
+	assert_eq!(
+		view.iter().map(|a| a.value).collect::<Vec<_>>(),
+		[100, 20, 40]
+	);
+}
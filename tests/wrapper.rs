@@ -0,0 +1,66 @@
+use cluFullTransmute::wrapper::TransparentWrapper;
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(u32);
+
+unsafe impl TransparentWrapper<u32> for Meters {}
+
+#[test]
+fn wrapper_ref_slice() {
+	let mut data: [u32; 3] = [1, 2, 3];
+
+	let meters: &mut [Meters] = Meters::wrap_slice_mut(&mut data);
+	meters[0] = Meters(10);
+	assert_eq!(Meters::peel_slice(meters), [10, 2, 3]);
+
+	Meters::wrap_mut(&mut data[1]).0 = 20;
+	assert_eq!(Meters::wrap_ref(&data[1]), &Meters(20));
+	assert_eq!(Meters::peel(Meters::wrap(5)), 5);
+}
+
+#[cfg(feature = "contract")]
+#[test]
+fn wrapper_contract() {
+	use cluFullTransmute::contract::TransmuteContract;
+
+	let contract: TransmuteContract<u32, u32> = TransparentWrapper::wrap(7);
+	let data: &[TransmuteContract<u32, u32>] = TransparentWrapper::wrap_slice(&[1, 2]);
+
+	assert_eq!(contract.into(), 7);
+	assert_eq!(*data[1].as_out(), 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn wrapper_alloc() {
+	let meters: Vec<Meters> = Meters::wrap_vec(vec![1, 2, 3]);
+	assert_eq!(meters, [Meters(1), Meters(2), Meters(3)]);
+	assert_eq!(Meters::peel_vec(meters), [1, 2, 3]);
+
+	let meters: Box<Meters> = Meters::wrap_box(Box::new(4));
+	assert_eq!(*Meters::peel_box(meters), 4);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn wrapper_derive() {
+	use core::marker::PhantomData;
+
+	#[repr(transparent)]
+	#[derive(TransparentWrapper, Debug, PartialEq)]
+	struct Id(u64);
+
+	#[repr(transparent)]
+	#[derive(TransparentWrapper, Debug, PartialEq)]
+	struct Tagged<T> {
+		_tag: PhantomData<T>,
+		#[transparent_wrapper]
+		value: u16,
+	}
+
+	assert_eq!(Id::wrap_slice(&[1, 2]), [Id(1), Id(2)]);
+
+	let tagged: &Tagged<String> = Tagged::wrap_ref(&3);
+	assert_eq!(tagged.value, 3);
+}